@@ -0,0 +1,4 @@
+fn main() {
+    // the migrations are embedded into the binary, see src/db/connection.rs
+    println!("cargo:rerun-if-changed=migrations");
+}
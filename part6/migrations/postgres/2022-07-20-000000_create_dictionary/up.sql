@@ -1,7 +1,8 @@
-create table dictionary (
+-- databases set up before the migrations were embedded already have this table
+create table if not exists dictionary (
     id serial primary key,
     word varchar not null,
     used_at DATE null,
     guessed boolean default 'f' not null,
     language varchar not null
-)
+);
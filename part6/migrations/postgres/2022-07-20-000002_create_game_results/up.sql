@@ -0,0 +1,7 @@
+create table game_results (
+    id serial primary key,
+    played_at DATE not null,
+    won boolean not null,
+    attempts integer not null,
+    language varchar not null
+);
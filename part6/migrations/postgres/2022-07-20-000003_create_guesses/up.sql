@@ -0,0 +1,5 @@
+create table guesses (
+    id serial primary key,
+    dictionary_id integer not null references dictionary(id),
+    guess varchar not null
+);
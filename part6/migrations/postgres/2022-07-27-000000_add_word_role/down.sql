@@ -0,0 +1 @@
+alter table dictionary drop column role;
@@ -0,0 +1 @@
+alter table game_results drop column kind;
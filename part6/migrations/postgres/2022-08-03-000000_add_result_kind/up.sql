@@ -0,0 +1 @@
+alter table game_results add column kind varchar default 'daily' not null;
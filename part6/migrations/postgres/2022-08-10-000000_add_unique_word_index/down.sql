@@ -0,0 +1 @@
+drop index dictionary_word_language;
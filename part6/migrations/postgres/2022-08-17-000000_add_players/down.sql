@@ -0,0 +1,4 @@
+alter table game_results drop column player_id;
+alter table guesses drop column player_id;
+drop table results;
+drop table players;
@@ -0,0 +1,17 @@
+create table players (
+    id serial primary key,
+    name varchar not null unique
+);
+
+create table results (
+    id serial primary key,
+    player_id integer not null references players(id),
+    dictionary_id integer not null references dictionary(id),
+    played_at DATE not null,
+    guessed boolean default 'f' not null,
+    lost boolean default 'f' not null,
+    unique (player_id, dictionary_id, played_at)
+);
+
+alter table guesses add column player_id integer null references players(id);
+alter table game_results add column player_id integer null references players(id);
@@ -0,0 +1,19 @@
+create table daily_words (
+    id serial primary key,
+    played_at DATE not null,
+    language varchar not null,
+    word_len integer not null,
+    dictionary_id integer not null references dictionary(id),
+    guessed boolean default 'f' not null,
+    lost boolean default 'f' not null,
+    unique (played_at, language, word_len)
+);
+
+-- only the last day a word was used at is known, earlier days have been overwritten
+insert into daily_words (played_at, language, word_len, dictionary_id, guessed, lost)
+    select used_at, language, length(word), id, guessed, lost from dictionary
+    where id in (select min(id) from dictionary where used_at is not null group by used_at, language, length(word));
+
+alter table guesses add column daily_word_id integer null references daily_words(id);
+
+update guesses set daily_word_id = (select daily_words.id from daily_words where daily_words.dictionary_id = guesses.dictionary_id);
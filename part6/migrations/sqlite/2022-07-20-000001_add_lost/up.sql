@@ -0,0 +1 @@
+alter table dictionary add column lost boolean default 0 not null;
@@ -0,0 +1,5 @@
+create table guesses (
+    id integer primary key not null,
+    dictionary_id integer not null references dictionary(id),
+    guess varchar not null
+);
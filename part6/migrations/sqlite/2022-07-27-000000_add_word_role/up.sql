@@ -0,0 +1 @@
+alter table dictionary add column role varchar default 'solution' not null;
@@ -0,0 +1 @@
+create unique index dictionary_word_language on dictionary (word, language);
@@ -0,0 +1,17 @@
+create table players (
+    id integer primary key not null,
+    name varchar not null unique
+);
+
+create table results (
+    id integer primary key not null,
+    player_id integer not null references players(id),
+    dictionary_id integer not null references dictionary(id),
+    played_at DATE not null,
+    guessed boolean default 0 not null,
+    lost boolean default 0 not null,
+    unique (player_id, dictionary_id, played_at)
+);
+
+alter table guesses add column player_id integer null references players(id);
+alter table game_results add column player_id integer null references players(id);
@@ -0,0 +1,2 @@
+alter table guesses drop column daily_word_id;
+drop table daily_words;
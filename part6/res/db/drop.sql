@@ -1 +0,0 @@
-drop table dictionary;
\ No newline at end of file
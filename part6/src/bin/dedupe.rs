@@ -0,0 +1,84 @@
+use std::env;
+use std::process::exit;
+
+use clap::Parser;
+use dotenv::dotenv;
+
+use fancy_hangman::db::connection::DbConnection;
+use fancy_hangman::db::db_maintenance::{find_duplicates, is_unique_violation, merge_duplicates};
+
+/// Merge words stored more than once for the same language in the database at DATABASE_URL,
+/// then create the unique index that prevents new duplicates
+#[derive(Parser)]
+struct Arguments {
+    /// Only list the duplicates, without changing the database
+    #[clap(long)]
+    dry_run: bool
+}
+
+fn main() {
+    let args = Arguments::parse();
+
+    dotenv().ok();
+    let db_url = match env::var("DATABASE_URL") {
+        Ok(db_url) => db_url,
+        Err(_) => {
+            println!("DATABASE_URL must be set");
+            exit(1);
+        }
+    };
+
+    let conn = match DbConnection::establish(&db_url) {
+        Ok(conn) => conn,
+        Err(error) => {
+            println!("Error connecting to the database: {}", error);
+            exit(1);
+        }
+    };
+
+    // every migration runs in a transaction of its own, so the ones before the unique index are
+    // applied even though creating the index fails while duplicates exist
+    if !args.dry_run {
+        match conn.run_migrations() {
+            Err(error) if is_unique_violation(&error) => println!("Creating the unique index failed, merging duplicates first."),
+            Err(error) => {
+                println!("Error migrating the database: {}", error);
+                exit(1);
+            },
+            Ok(_) => ()
+        }
+    }
+
+    let duplicates = match args.dry_run {
+        true => find_duplicates(&conn),
+        false => merge_duplicates(&conn)
+    };
+
+    let duplicates = match duplicates {
+        Ok(duplicates) => duplicates,
+        Err(error) => {
+            println!("Database error: {}", error);
+            exit(1);
+        }
+    };
+
+    for duplicate in &duplicates {
+        println!(
+            "'{}' ({}) is stored {} times, {} row {}",
+            duplicate.word, duplicate.language, duplicate.removed_ids.len() + 1,
+            if args.dry_run { "keeping" } else { "kept" }, duplicate.kept_id
+        );
+    }
+
+    if args.dry_run {
+        println!("Found {} duplicate words.", duplicates.len());
+        return;
+    }
+
+    println!("Merged {} duplicate words.", duplicates.len());
+
+    if let Err(error) = conn.run_migrations() {
+        println!("Error migrating the database: {}", error);
+        exit(1);
+    }
+}
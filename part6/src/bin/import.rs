@@ -8,7 +8,7 @@ use console::{Emoji, style};
 use indicatif::{HumanDuration, ProgressBar, ProgressStyle};
 use uuid::Uuid;
 
-use fancy_hangman::dictionary::{Dictionary, DictionaryEntry, get_dictionary};
+use fancy_hangman::dictionary::{CreatedWords, Dictionary, DictionaryEntry, DictionaryError, get_dictionary, try_parse_word_role, WordRole};
 use fancy_hangman::lang::locale::{AppLanguage, get_app_language, parse_app_language, replace_unicode};
 
 static BOOKMARK: Emoji<'_, '_> = Emoji("🔖  ", "");
@@ -20,9 +20,15 @@ static SPARKLE: Emoji<'_, '_> = Emoji("✨ ", ":-)");
 struct Arguments {
     source_file: String,
     language: Option<String>,
-    dictionary: Option<String>
+    dictionary: Option<String>,
+    /// Keep words with this number of characters, can be repeated. Defaults to 5
+    #[clap(long)]
+    length: Vec<usize>,
+    /// "solution" to import words that can be drawn as the word to guess, "guess" for words that are only accepted as guesses
+    #[clap(long, default_value = "solution", parse(try_from_str = try_parse_word_role))]
+    role: WordRole
 }
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Arguments::parse();
 
     let app_language = match args.language {
@@ -31,8 +37,13 @@ fn main() -> std::io::Result<()> {
     };
 
     let dictionary: Box<dyn Dictionary> = match args.dictionary {
-        None => get_dictionary(app_language, String::from("text")),
-        Some(flag) => get_dictionary(app_language, flag)
+        None => get_dictionary(app_language, String::from("text"))?,
+        Some(flag) => get_dictionary(app_language, flag)?
+    };
+
+    let word_lengths: Vec<usize> = match args.length.is_empty() {
+        true => vec![5],
+        false => args.length
     };
 
     let started = Instant::now();
@@ -52,18 +63,21 @@ fn main() -> std::io::Result<()> {
     let progress_polish = setup_spinner();
     progress_polish.set_message(format!("Processing {}...", &args.source_file));
 
-    let meta_data = polish(&args.source_file, app_language)?;
+    let meta_data = polish(&args.source_file, app_language, &word_lengths)?;
 
     progress_polish.finish_with_message(format!("Finished processing {}. Importing...", &args.source_file));
 
     let progress_import = ProgressBar::new(meta_data.1);
 
-    let counter = import(meta_data.0, dictionary, &progress_import)?;
+    let created = import(meta_data.0, dictionary, args.role, &progress_import)?;
 
     progress_polish.finish_and_clear();
     progress_import.finish_and_clear();
 
-    println!("{} Done in {}. Added {} words to the dictionary!", SPARKLE, HumanDuration(started.elapsed()), counter);
+    println!(
+        "{} Done in {}. Added {} words to the dictionary, skipped {} words that were already part of it!",
+        SPARKLE, HumanDuration(started.elapsed()), created.inserted, created.skipped
+    );
 
     Ok(())
 }
@@ -77,23 +91,26 @@ fn main() -> std::io::Result<()> {
 ///
 /// * `src_path` - A string slice that holds the path of the file you want to import on the filesystem
 /// * `app_language` - The language of the imported words. See [AppLanguage]
-fn polish(source_path: &str, app_language: AppLanguage) -> Result<(String, u64), Error> {
+/// * `word_lengths` - The lengths of the words to keep, all other words are discarded
+fn polish(source_path: &str, app_language: AppLanguage, word_lengths: &[usize]) -> Result<(String, u64), Error> {
+    // open the source first, so no temporary file is left behind if it is missing
+    let buf_reader = BufReader::new(File::open(source_path)?);
+
     let tmp_file_name = format!("{}/{}.txt", temp_dir().to_str().unwrap(), Uuid::new_v4());
     let out_file: Result<File, Error> = File::create(&tmp_file_name);
 
     match out_file {
         Ok(out_file) => {
-            let buf_reader = BufReader::new(File::open(source_path).unwrap());
             let mut writer: LineWriter<File> = LineWriter::new(out_file);
 
             let mut counter = 0;
 
             for line_result in buf_reader.lines() {
-                let polished = replace_unicode(line_result.unwrap().to_lowercase().as_str(), app_language);
+                let polished = replace_unicode(line_result?.to_lowercase().as_str(), app_language);
 
-                if polished.len() == 5 {
-                    writer.write(polished.as_ref())?;
-                    writer.write(b"\n")?;
+                if word_lengths.contains(&polished.len()) {
+                    writer.write_all(polished.as_ref())?;
+                    writer.write_all(b"\n")?;
 
                     counter += 1;
                 }
@@ -105,33 +122,31 @@ fn polish(source_path: &str, app_language: AppLanguage) -> Result<(String, u64),
     }
 }
 
-/// Import temporary file created by [polish] into the dictionary.
-/// Avoid duplicates when inserting a [WordEntry] into the dictionary.
+/// Import temporary file created by [polish] into the dictionary with [Dictionary::create_words].
+/// Words already part of the dictionary are skipped, any other error aborts the import.
 ///
 /// # Arguments
 ///
 /// * `tmp_file_name` - A String that holds the name of the temp file created
-fn import(tmp_file_name: String, dictionary: Box<dyn Dictionary>, progress_bar: &ProgressBar) -> Result<i32, Error> {
-    let buf_reader = BufReader::new(File::open(tmp_file_name).unwrap());
-
-    let mut counter = 0;
-    for line_result in buf_reader.lines() {
-        let line = line_result.unwrap();
-
-        match dictionary.create_word(DictionaryEntry {
+/// * `role` - The [WordRole] of the imported words
+fn import(tmp_file_name: String, dictionary: Box<dyn Dictionary>, role: WordRole, progress_bar: &ProgressBar) -> Result<CreatedWords, DictionaryError> {
+    let buf_reader = BufReader::new(File::open(tmp_file_name)?);
+    let lines: Vec<String> = buf_reader.lines().collect::<Result<Vec<String>, Error>>()?;
+
+    let mut word_entries = lines.into_iter()
+        .inspect(|_| progress_bar.inc(1))
+        .map(|line| DictionaryEntry {
             word: line.to_lowercase(),
-            guessed: false
-        }) {
-            None => {},
-            Some(_) => {
-                counter += 1;
-            }
-        }
+            guessed: false,
+            lost: false,
+            role
+        });
 
-        progress_bar.inc(1);
-    }
+    let created = dictionary.create_words(&mut word_entries)?;
+
+    dictionary.flush()?;
 
-    Ok(counter)
+    Ok(created)
 }
 
 fn setup_spinner() -> ProgressBar {
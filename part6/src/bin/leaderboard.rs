@@ -0,0 +1,79 @@
+use std::env;
+use std::process::exit;
+
+use clap::Parser;
+use dotenv::dotenv;
+
+use fancy_hangman::db::db_statistics::DbStatisticsStore;
+use fancy_hangman::game::daily::get_day_boundary;
+use fancy_hangman::lang::locale::{get_app_language, parse_app_language};
+use fancy_hangman::leaderboard::{format_leaderboard, Leaderboard, parse_leaderboard_format, parse_period};
+use fancy_hangman::statistics::ResultKind;
+
+/// Rank the players sharing the database at DATABASE_URL
+#[derive(Parser)]
+struct Arguments {
+    language: Option<String>,
+    /// The games to rank the players by: "week", "month" or "all"
+    #[clap(long, default_value = "week")]
+    period: String,
+    /// "table", "csv" or "json"
+    #[clap(long, default_value = "table")]
+    format: String,
+    /// Rank the players by replayed puzzles instead of the daily game
+    #[clap(long)]
+    archive: bool,
+    /// When the next word starts: "local", "utc", an offset like "+02:00" or a timezone like "Europe/Vienna". Defaults to WORDLE_TIMEZONE or utc
+    #[clap(long)]
+    timezone: Option<String>
+}
+
+fn main() {
+    let args = Arguments::parse();
+
+    let app_language = match args.language {
+        None => get_app_language(),
+        Some(flag) => parse_app_language(flag.as_str())
+    };
+
+    let (period, format) = match (parse_period(&args.period), parse_leaderboard_format(&args.format)) {
+        (Ok(period), Ok(format)) => (period, format),
+        (Err(error), _) | (_, Err(error)) => {
+            println!("{}", error);
+            exit(1);
+        }
+    };
+
+    let today = match get_day_boundary(args.timezone) {
+        Ok(day_boundary) => day_boundary.today(),
+        Err(error) => {
+            println!("{}", error);
+            exit(1);
+        }
+    };
+
+    let kind = match args.archive {
+        true => ResultKind::Archive,
+        false => ResultKind::Daily
+    };
+
+    dotenv().ok();
+    let db_url = match env::var("DATABASE_URL") {
+        Ok(db_url) => db_url,
+        Err(_) => {
+            println!("DATABASE_URL must be set");
+            exit(1);
+        }
+    };
+
+    let results = DbStatisticsStore::new(db_url, app_language, kind)
+        .and_then(|statistics_store| statistics_store.get_player_results(period.start(today)));
+
+    match results {
+        Ok(results) => println!("{}", format_leaderboard(&Leaderboard::new(&results, period, today), format)),
+        Err(error) => {
+            println!("Error reading the game results:\n{}", error);
+            exit(1);
+        }
+    }
+}
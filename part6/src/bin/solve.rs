@@ -0,0 +1,79 @@
+use std::process::exit;
+
+use clap::Parser;
+
+use fancy_hangman::dictionary::{Dictionary, get_dictionary, WordFilter};
+use fancy_hangman::game::feedback::GuessFeedback;
+use fancy_hangman::game::solver::{format_hint, hint, parse_feedback};
+use fancy_hangman::lang::locale::{get_app_language, parse_app_language, replace_unicode};
+
+/// List the words matching the feedback of your guesses and suggest the next guess
+#[derive(Parser)]
+struct Arguments {
+    language: Option<String>,
+    dictionary: Option<String>,
+    /// A guess and its feedback: G (correct), Y (present) or - (absent) for every letter, e.g. crane:-Y--G. Can be repeated
+    #[clap(long = "guess")]
+    guesses: Vec<String>,
+    /// The number of characters of the solution, used when no guess is given
+    #[clap(long, default_value_t = 5)]
+    length: usize,
+    /// Only suggest guesses that are valid in hard mode
+    #[clap(long)]
+    hard: bool,
+    /// The maximum number of possible words to list
+    #[clap(long, default_value_t = 20)]
+    limit: usize
+}
+
+fn main() {
+    let args = Arguments::parse();
+
+    let app_language = match args.language {
+        None => get_app_language(),
+        Some(flag) => parse_app_language(flag.as_str())
+    };
+
+    let dictionary: Box<dyn Dictionary> = match get_dictionary(app_language, args.dictionary.unwrap_or_else(|| String::from("text"))) {
+        Ok(dictionary) => dictionary,
+        Err(error) => {
+            println!("Error opening the dictionary:\n{}", error);
+            exit(1);
+        }
+    };
+
+    let mut guesses: Vec<GuessFeedback> = Vec::new();
+
+    for guess in &args.guesses {
+        let feedback = match guess.split_once(':') {
+            Some((word, pattern)) => parse_feedback(&replace_unicode(&word.to_lowercase(), app_language), pattern),
+            None => Err(format!("'{}' must be a guess and its feedback separated by ':', e.g. crane:-Y--G", guess))
+        };
+
+        match feedback {
+            Ok(feedback) => guesses.push(feedback),
+            Err(error) => {
+                println!("{}", error);
+                exit(1);
+            }
+        }
+    }
+
+    let word_len = match guesses.first() {
+        Some(feedback) => feedback.letters.len(),
+        None => args.length
+    };
+
+    if guesses.iter().any(|feedback| feedback.letters.len() != word_len) {
+        println!("All guesses must have a size of {} characters.", word_len);
+        exit(1);
+    }
+
+    match dictionary.get_entries(&WordFilter::new().with_word_len(word_len)) {
+        Ok(words) => println!("{}", format_hint(&hint(&words, &guesses, args.hard), args.limit)),
+        Err(error) => {
+            println!("Error reading the dictionary:\n{}", error);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::process::exit;
+
+use clap::Parser;
+
+use fancy_hangman::game::daily::get_day_boundary;
+use fancy_hangman::lang::locale::{get_app_language, parse_app_language};
+use fancy_hangman::player::get_player_name;
+use fancy_hangman::statistics::{format_statistics, get_statistics_store, ResultKind, Statistics};
+
+/// Show your wordle statistics
+#[derive(Parser)]
+struct Arguments {
+    language: Option<String>,
+    dictionary: Option<String>,
+    /// Show the statistics of replayed puzzles instead of the daily game
+    #[clap(long)]
+    archive: bool,
+    /// When the next word starts: "local", "utc", an offset like "+02:00" or a timezone like "Europe/Vienna". Defaults to WORDLE_TIMEZONE or utc
+    #[clap(long)]
+    timezone: Option<String>,
+    /// Your name when sharing a database dictionary with others. Defaults to WORDLE_PLAYER or the name of the OS user
+    #[clap(long)]
+    player: Option<String>
+}
+
+fn main() {
+    let args = Arguments::parse();
+
+    let app_language = match args.language {
+        None => get_app_language(),
+        Some(flag) => parse_app_language(flag.as_str())
+    };
+
+    let player = get_player_name(args.player);
+
+    let kind = match args.archive {
+        true => ResultKind::Archive,
+        false => ResultKind::Daily
+    };
+
+    let dictionary_flag = args.dictionary.unwrap_or_else(|| String::from("text"));
+
+    let results = get_statistics_store(app_language, dictionary_flag, kind, &player)
+        .and_then(|statistics_store| statistics_store.get_results());
+
+    let today = match get_day_boundary(args.timezone) {
+        Ok(day_boundary) => day_boundary.today(),
+        Err(error) => {
+            println!("{}", error);
+            exit(1);
+        }
+    };
+
+    match results {
+        Ok(results) => println!("{}", format_statistics(&Statistics::new(&results, today), 30)),
+        Err(error) => {
+            println!("Error reading the statistics:\n{}", error);
+            exit(1);
+        }
+    }
+}
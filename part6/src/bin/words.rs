@@ -0,0 +1,82 @@
+use std::process::exit;
+
+use clap::Parser;
+
+use fancy_hangman::dictionary::{Dictionary, get_dictionary, try_parse_word_role, WordFilter, WordRole};
+use fancy_hangman::lang::locale::{get_app_language, parse_app_language};
+
+/// List or count the words of the dictionary
+#[derive(Parser)]
+struct Arguments {
+    language: Option<String>,
+    dictionary: Option<String>,
+    /// Only words with this number of characters
+    #[clap(long)]
+    length: Option<usize>,
+    /// Only words with this role: "solution" or "guess"
+    #[clap(long, parse(try_from_str = try_parse_word_role))]
+    role: Option<WordRole>,
+    /// Only words that have been the word of a day
+    #[clap(long, conflicts_with = "unused")]
+    used: bool,
+    /// Only words that have not been the word of a day yet
+    #[clap(long)]
+    unused: bool,
+    /// Only words that have been guessed
+    #[clap(long)]
+    guessed: bool,
+    /// Only words matching the pattern: "_" matches a single character, "*" any number of characters, e.g. "r_st*"
+    #[clap(long)]
+    pattern: Option<String>,
+    /// Print the number of words instead of the words
+    #[clap(long)]
+    count: bool
+}
+
+fn main() {
+    let args = Arguments::parse();
+
+    let app_language = match args.language {
+        None => get_app_language(),
+        Some(flag) => parse_app_language(flag.as_str())
+    };
+
+    let dictionary: Box<dyn Dictionary> = match get_dictionary(app_language, args.dictionary.unwrap_or_else(|| String::from("text"))) {
+        Ok(dictionary) => dictionary,
+        Err(error) => {
+            println!("Error opening the dictionary:\n{}", error);
+            exit(1);
+        }
+    };
+
+    let mut filter = WordFilter::new();
+    if let Some(length) = args.length {
+        filter = filter.with_word_len(length);
+    }
+    if let Some(role) = args.role {
+        filter = filter.with_role(role);
+    }
+    if args.used || args.unused {
+        filter = filter.with_used(args.used);
+    }
+    if args.guessed {
+        filter = filter.with_guessed(true);
+    }
+    if let Some(pattern) = args.pattern {
+        filter = filter.with_pattern(&pattern);
+    }
+
+    let result = match args.count {
+        true => dictionary.count_words(&filter).map(|count| println!("{}", count)),
+        false => dictionary.get_entries(&filter).map(|entries| {
+            for entry in entries {
+                println!("{}", entry.word);
+            }
+        })
+    };
+
+    if let Err(error) = result {
+        println!("Error reading the dictionary:\n{}", error);
+        exit(1);
+    }
+}
@@ -1,144 +1,507 @@
+use std::collections::HashSet;
+
 use chrono::NaiveDate;
 use diesel::dsl::sql;
-use diesel::{Connection, ExpressionMethods, OptionalExtension, SqliteConnection, QueryDsl, RunQueryDsl};
-use diesel::result::Error;
+use diesel::{Connection, EscapeExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, TextExpressionMethods};
+use diesel::result::{DatabaseErrorKind, Error};
 
-use crate::db::model::{DbDictionaryEntry, NewDbDictionaryEntry};
-use crate::db::schema::dictionary;
-use crate::dictionary::{Dictionary, DictionaryEntry};
+use crate::db::connection::DbConnection;
+use crate::db::db_maintenance::find_duplicates;
+use crate::db::db_player::get_player_id;
+use crate::db::functions::length;
+use crate::db::model::{DbDailyWord, DbDictionaryEntry, NewDbDailyWord, NewDbDictionaryEntry, NewDbGuess, NewDbResult};
+use crate::db::schema::{daily_words, dictionary, guesses, results};
+use crate::dictionary::{CreatedWords, Dictionary, DictionaryEntry, DictionaryError, parse_word_role, WordFilter, WordRole};
+use crate::game::daily::daily_index;
 use crate::lang::locale::AppLanguage;
 
+/// The number of words inserted per transaction by [DbDictionary::create_words].
+/// Small enough to stay below the limit of bind parameters of SQLite.
+const CHUNK_SIZE: usize = 250;
+
+/// Build a boxed query of the entries matching a [WordFilter]. This is a macro rather than a
+/// method, so the query is boxed for the backend of the connection it is run against.
+macro_rules! filter_entries {
+    ($filter:expr, $app_language:expr) => {{
+        let filter: &WordFilter = $filter;
+        let mut query = dictionary::table
+            .into_boxed()
+            .filter(dictionary::language.eq(filter.language.unwrap_or($app_language).to_string()));
+
+        if let Some(word_len) = filter.word_len {
+            query = query.filter(length(dictionary::word).eq(word_len as i32));
+        }
+        if let Some(role) = filter.role {
+            query = query.filter(dictionary::role.eq(role.to_string()));
+        }
+        match filter.used {
+            Some(true) => query = query.filter(dictionary::used_at.is_not_null()),
+            Some(false) => query = query.filter(dictionary::used_at.is_null()),
+            None => ()
+        }
+        if let Some(guessed) = filter.guessed {
+            query = query.filter(dictionary::guessed.eq(guessed));
+        }
+        if let Some(pattern) = &filter.pattern {
+            query = query.filter(dictionary::word.like(to_like_pattern(pattern)).escape('\\'));
+        }
+
+        query
+    }};
+}
+
+/// Translate the wildcards of [crate::dictionary::matches_pattern] to LIKE. `%` and `\` match
+/// themselves there, so they are escaped with `\`, the escape character given to LIKE.
+fn to_like_pattern(pattern: &str) -> String {
+    pattern.replace('\\', "\\\\").replace('%', "\\%").replace('*', "%")
+}
+
 pub struct DbDictionary {
-    conn: SqliteConnection,
-    app_language: AppLanguage
+    conn: DbConnection,
+    app_language: AppLanguage,
+    player_id: Option<i32>
 }
 
 impl DbDictionary {
-    pub fn new(db_url: String, app_language: AppLanguage) -> DbDictionary {
-        DbDictionary {
-            conn: SqliteConnection::establish(&db_url)
-                .expect(&format!("Error connecting to database {}", db_url)),
-            app_language
+    /// Connect to the database dictionary given, see [DbConnection::establish].
+    /// Pending migrations are applied right after connecting. They fail while words are stored
+    /// more than once, see [crate::db::db_maintenance::merge_duplicates].
+    ///
+    /// # Arguments
+    /// * `db_url` - A String that holds the url of a PostgreSQL database or the path of a SQLite database
+    /// * `app_language` - The language of the words. See [AppLanguage]
+    pub fn new(db_url: String, app_language: AppLanguage) -> Result<DbDictionary, DictionaryError> {
+        let conn = DbConnection::establish(&db_url)?;
+
+        if let Err(error) = conn.run_migrations() {
+            // creating the unique index on word and language fails while duplicates exist
+            return match find_duplicates(&conn) {
+                Ok(duplicates) if !duplicates.is_empty() => Err(DictionaryError::Configuration(format!(
+                    "The dictionary contains {} duplicate words, merge them with the dedupe tool first", duplicates.len()
+                ))),
+                _ => Err(DictionaryError::from(error))
+            };
         }
+
+        Ok(DbDictionary {
+            conn,
+            app_language,
+            player_id: None
+        })
     }
 
-    fn update_entry(&self, entry: &DbDictionaryEntry) {
-        match diesel::update(dictionary::dsl::dictionary
-            .filter(dictionary::id.eq(entry.id)))
-            .set((dictionary::used_at.eq(entry.used_at), dictionary::guessed.eq(entry.guessed)))
-            .execute(&self.conn) {
-                Ok(affected_rows) => if affected_rows <= 0 { println!("No rows were affected when updating {}", entry.id) },
-                Err(error) => println!("Error when updating entry with id {}:\n{}", entry.id, error)
-        }
+    /// Keep the games of the player given apart from the games of other players sharing the database.
+    /// Whether the word of a day has been guessed or lost and the guesses submitted are stored per player,
+    /// the word of the day itself is the same for everyone. The player is created if it does not exist yet.
+    /// Without a player, the state of the game is stored with the word, shared by everyone.
+    ///
+    /// # Arguments
+    /// * `name` - A string slice that holds the name of the player
+    pub fn with_player(mut self, name: &str) -> Result<DbDictionary, DictionaryError> {
+        self.player_id = Some(get_player_id(&self.conn, name)?);
+
+        Ok(self)
+    }
+
+    fn get_entry(&self, id: i32) -> Result<DbDictionaryEntry, Error> {
+        with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
+            .find(id)
+            .get_result::<DbDictionaryEntry>(conn))
+    }
+
+    fn find_entry(&self, text: &str) -> Result<Option<DbDictionaryEntry>, Error> {
+        with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
+            .filter(dictionary::word.eq(text))
+            .filter(dictionary::language.eq(&self.app_language.to_string()))
+            .get_result::<DbDictionaryEntry>(conn)
+            .optional())
     }
 
-    fn get_word_of_today(&self, current_day: NaiveDate) -> Result<Option<DbDictionaryEntry>, Error> {
-        match dictionary::dsl::dictionary
-            .filter(dictionary::used_at.eq(current_day))
+    fn count_solutions(&self, word_len: usize) -> Result<i64, Error> {
+        with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
             .filter(dictionary::language.eq(&self.app_language.to_string()))
+            .filter(dictionary::role.eq(WordRole::Solution.to_string()))
+            .filter(length(dictionary::word).eq(word_len as i32))
+            .count()
+            .get_result::<i64>(conn))
+    }
+
+    /// Return the solution at index in alphabetical order
+    fn get_solution_at(&self, word_len: usize, index: usize) -> Result<Option<DbDictionaryEntry>, Error> {
+        with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
+            .filter(dictionary::language.eq(&self.app_language.to_string()))
+            .filter(dictionary::role.eq(WordRole::Solution.to_string()))
+            .filter(length(dictionary::word).eq(word_len as i32))
+            .order(dictionary::word)
+            .offset(index as i64)
             .limit(1)
-            .get_result::<DbDictionaryEntry>(&self.conn)
-            .optional() {
-                Err(error) => Err(error),
-                Ok(result) => match result {
-                    Some(entry) => Ok(Some(entry)),
-                    None => dictionary::dsl::dictionary
-                        .filter(dictionary::used_at.is_null())
-                        .filter(dictionary::language.eq(&self.app_language.to_string()))
-                        .order(sql::<()>("RANDOM()"))
-                        .limit(1)
-                        .get_result::<DbDictionaryEntry>(&self.conn)
-                        .optional()
-            }
+            .get_result::<DbDictionaryEntry>(conn)
+            .optional())
+    }
+
+    /// Return the word of the day drawn for the day and length given, if any game has been started for it
+    fn find_daily_word(&self, day: NaiveDate, word_len: usize) -> Result<Option<DbDailyWord>, Error> {
+        with_connection!(&self.conn, |conn| daily_words::dsl::daily_words
+            .filter(daily_words::played_at.eq(day))
+            .filter(daily_words::language.eq(&self.app_language.to_string()))
+            .filter(daily_words::word_len.eq(word_len as i32))
+            .select((daily_words::id, daily_words::played_at, daily_words::dictionary_id, daily_words::guessed, daily_words::lost))
+            .get_result::<DbDailyWord>(conn)
+            .optional())
+    }
+
+    /// Return the last day the word given has been the word of the day
+    fn find_last_daily_word(&self, text: &str) -> Result<Option<DbDailyWord>, Error> {
+        with_connection!(&self.conn, |conn| daily_words::dsl::daily_words
+            .inner_join(dictionary::table)
+            .filter(dictionary::word.eq(text))
+            .filter(daily_words::language.eq(&self.app_language.to_string()))
+            .order(daily_words::played_at.desc())
+            .select((daily_words::id, daily_words::played_at, daily_words::dictionary_id, daily_words::guessed, daily_words::lost))
+            .limit(1)
+            .get_result::<DbDailyWord>(conn)
+            .optional())
+    }
+
+    /// Store the word of a day. Another game drawing the same day at the same time is not an error,
+    /// the unique index on day, language and length keeps whichever word was stored first.
+    fn insert_daily_word(&self, new_daily_word: &NewDbDailyWord) -> Result<(), Error> {
+        match &self.conn {
+            DbConnection::Sqlite(conn) => diesel::insert_or_ignore_into(daily_words::table)
+                .values(new_daily_word)
+                .execute(conn),
+            DbConnection::Postgres(conn) => diesel::insert_into(daily_words::table)
+                .values(new_daily_word)
+                .on_conflict_do_nothing()
+                .execute(conn)
+        }?;
+
+        Ok(())
+    }
+
+    /// Mark the word as used at the day given, unless it has been used at a later day already.
+    /// The state stored with the word is the state of the last game it has been the solution of.
+    fn mark_used(&self, entry: &DbDictionaryEntry, day: NaiveDate) -> Result<(), Error> {
+        if entry.used_at.is_some_and(|used_at| used_at >= day) {
+            return Ok(());
         }
+
+        with_connection!(&self.conn, |conn| diesel::update(dictionary::dsl::dictionary
+            .filter(dictionary::id.eq(entry.id)))
+            .set((dictionary::used_at.eq(day), dictionary::guessed.eq(false), dictionary::lost.eq(false)))
+            .execute(conn))?;
+
+        Ok(())
     }
-}
 
-impl Dictionary for DbDictionary {
-    /// Return a randomly selected word that has not been used before.
-    /// If found, the database entry will be updated with a [chrono::NaiveDate] matching today.
-    fn get_random_word(&self) -> Option<DictionaryEntry> {
-        let current_day = chrono::Utc::now();
-        let current_day: NaiveDate = current_day.naive_utc().date();
-
-        match self.get_word_of_today(current_day) {
-            Ok(result) => match result {
-                None => None,
-                Some(mut entry) => {
-                    entry.used_at = Some(current_day);
-
-                    self.update_entry(&entry);
-                    Some(DictionaryEntry{
-                        word: entry.word,
-                        guessed: entry.guessed
-                    })
-                }
-            }
-            Err(error) => {
-                println!("Error when getting today's word.\n{}", error);
+    /// Insert the words given in a single transaction and return the number of rows inserted.
+    /// Rows conflicting with an existing row are left untouched.
+    fn insert_chunk(&self, new_words: &[NewDbDictionaryEntry]) -> Result<usize, Error> {
+        if new_words.is_empty() {
+            return Ok(0);
+        }
 
-                None
-            }
+        // diesel can't write ON CONFLICT DO NOTHING for SQLite, INSERT OR IGNORE is its equivalent there
+        match &self.conn {
+            DbConnection::Sqlite(conn) => conn.transaction(|| diesel::insert_or_ignore_into(dictionary::table)
+                .values(new_words)
+                .execute(conn)),
+            DbConnection::Postgres(conn) => conn.transaction(|| diesel::insert_into(dictionary::table)
+                .values(new_words)
+                .on_conflict_do_nothing()
+                .execute(conn))
         }
     }
 
-    fn find_word(&self, text: &str) -> Option<DictionaryEntry> {
-        let db_result = dictionary::dsl::dictionary
-            .filter(dictionary::word.eq(text))
-            .filter(dictionary::language.eq(&self.app_language.to_string()))
-            .get_result::<DbDictionaryEntry>(&self.conn)
-            .optional();
-
-        match db_result {
-            Ok(db_word) => match db_word {
-                Some(entry) => Some(DictionaryEntry {
-                    word: entry.word,
-                    guessed: entry.guessed
-                }),
-                None => None
-            },
-            Err(error) => {
-                println!("Error when looking for '{}' in the database:\n{}", text, error);
-
-                None
-            }
+    /// Convert the entry of the word of a day, reading whether it has been guessed or lost
+    /// from the results of the player, or from the day if there is no player
+    fn to_daily_entry(&self, entry: DbDictionaryEntry, daily_word: &DbDailyWord) -> Result<DictionaryEntry, Error> {
+        let (guessed, lost) = match self.player_id {
+            None => (daily_word.guessed, daily_word.lost),
+            Some(player_id) => with_connection!(&self.conn, |conn| results::dsl::results
+                .filter(results::player_id.eq(player_id))
+                .filter(results::dictionary_id.eq(daily_word.dictionary_id))
+                .filter(results::played_at.eq(daily_word.played_at))
+                .select((results::guessed, results::lost))
+                .get_result::<(bool, bool)>(conn)
+                .optional())?
+                .unwrap_or((false, false))
+        };
+
+        Ok(DictionaryEntry {
+            guessed,
+            lost,
+            ..DictionaryEntry::from(entry)
+        })
+    }
+
+    /// Store whether the last game of the word has been guessed or lost, for the player if there is one
+    fn save_state(&self, word_entry: &DictionaryEntry, guessed: bool, lost: bool) -> Result<(), DictionaryError> {
+        // only the word of a day has a state
+        let daily_word = self.find_last_daily_word(&word_entry.word)?
+            .ok_or_else(|| DictionaryError::NotFound(String::from(&word_entry.word)))?;
+
+        if let Some(player_id) = self.player_id {
+            return self.save_result(player_id, &daily_word, guessed, lost);
         }
+
+        with_connection!(&self.conn, |conn| diesel::update(daily_words::dsl::daily_words
+            .filter(daily_words::id.eq(daily_word.id)))
+            .set((daily_words::guessed.eq(guessed), daily_words::lost.eq(lost)))
+            .execute(conn))?;
+
+        with_connection!(&self.conn, |conn| diesel::update(dictionary::dsl::dictionary
+            .filter(dictionary::id.eq(daily_word.dictionary_id)))
+            .set((dictionary::guessed.eq(guessed), dictionary::lost.eq(lost)))
+            .execute(conn))?;
+
+        Ok(())
     }
 
-    fn create_word(&self, word_entry: DictionaryEntry) -> Option<DictionaryEntry> {
-        match self.find_word(&word_entry.word) {
+    /// Store the result of the player for the day the word was the word of the day
+    fn save_result(&self, player_id: i32, daily_word: &DbDailyWord, guessed: bool, lost: bool) -> Result<(), DictionaryError> {
+        let affected_rows = with_connection!(&self.conn, |conn| diesel::update(results::dsl::results
+            .filter(results::player_id.eq(player_id))
+            .filter(results::dictionary_id.eq(daily_word.dictionary_id))
+            .filter(results::played_at.eq(daily_word.played_at)))
+            .set((results::guessed.eq(guessed), results::lost.eq(lost)))
+            .execute(conn))?;
+
+        if affected_rows == 0 {
+            let new_result = NewDbResult {
+                player_id,
+                dictionary_id: daily_word.dictionary_id,
+                played_at: daily_word.played_at,
+                guessed,
+                lost
+            };
+
+            with_connection!(&self.conn, |conn| diesel::insert_into(results::table)
+                .values(&new_result)
+                .execute(conn))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<DbDictionaryEntry> for DictionaryEntry {
+    fn from(entry: DbDictionaryEntry) -> Self {
+        DictionaryEntry {
+            word: entry.word,
+            guessed: entry.guessed,
+            lost: entry.lost,
+            role: parse_word_role(&entry.role)
+        }
+    }
+}
+
+impl Dictionary for DbDictionary {
+    /// Return a randomly selected solution with a length of word_len characters
+    fn get_random_word(&self, word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        // RANDOM() is understood by both SQLite and PostgreSQL
+        Ok(with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
+            .filter(dictionary::language.eq(&self.app_language.to_string()))
+            .filter(dictionary::role.eq(WordRole::Solution.to_string()))
+            .filter(length(dictionary::word).eq(word_len as i32))
+            .order(sql::<()>("RANDOM()"))
+            .limit(1)
+            .get_result::<DbDictionaryEntry>(conn)
+            .optional())?
+            .map(DictionaryEntry::from))
+    }
+
+    /// Return the solution of the day from the solutions with a length of word_len characters in alphabetical order.
+    /// The first game of a day stores its word, later games of that day get the stored word even if the
+    /// solutions have changed in the meantime. A word that has been the solution of another day before
+    /// starts as a new game, the games of the earlier days are kept.
+    fn get_daily_word(&self, day: NaiveDate, word_len: usize, seed: &str) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        let daily_word = match self.find_daily_word(day, word_len)? {
+            Some(daily_word) => daily_word,
             None => {
-                let new_word = NewDbDictionaryEntry {
-                    word: String::from(&word_entry.word),
-                    language: self.app_language.to_string()
-                };
+                let count = self.count_solutions(word_len)? as usize;
 
-                let db_result = diesel::insert_into(dictionary::table)
-                    .values(&new_word)
-                    .execute(&self.conn);
-
-                match db_result {
-                    Ok(_) => {
-                        Some(word_entry)
-                    },
-                    Err(e) => {
-                        println!("Error when writing '{}' to the database:\n{}", &new_word.word, e);
-                        None
+                let entry = match daily_index(day, self.app_language, seed, count) {
+                    None => return Ok(None),
+                    Some(index) => match self.get_solution_at(word_len, index)? {
+                        None => return Ok(None),
+                        Some(entry) => entry
                     }
-                }
-            },
-            Some (_) => None
+                };
+
+                self.insert_daily_word(&NewDbDailyWord {
+                    played_at: day,
+                    language: self.app_language.to_string(),
+                    word_len: word_len as i32,
+                    dictionary_id: entry.id
+                })?;
+
+                self.find_daily_word(day, word_len)?
+                    .ok_or(DictionaryError::NotFound(entry.word))?
+            }
+        };
+
+        let entry = self.get_entry(daily_word.dictionary_id)?;
+        self.mark_used(&entry, day)?;
+
+        Ok(Some(self.to_daily_entry(entry, &daily_word)?))
+    }
+
+    /// Return the days a word of the day has been drawn for before the day given
+    fn get_archive_days(&self, before: NaiveDate, word_len: usize) -> Result<Vec<NaiveDate>, DictionaryError> {
+        Ok(with_connection!(&self.conn, |conn| daily_words::dsl::daily_words
+            .select(daily_words::played_at)
+            .filter(daily_words::played_at.lt(before))
+            .filter(daily_words::language.eq(&self.app_language.to_string()))
+            .filter(daily_words::word_len.eq(word_len as i32))
+            .order(daily_words::played_at.desc())
+            .load::<NaiveDate>(conn))?)
+    }
+
+    fn get_archive_word(&self, day: NaiveDate, word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        let daily_word = match self.find_daily_word(day, word_len)? {
+            None => return Ok(None),
+            Some(daily_word) => daily_word
+        };
+
+        let entry = self.get_entry(daily_word.dictionary_id)?;
+
+        Ok(Some(self.to_daily_entry(entry, &daily_word)?))
+    }
+
+    fn get_entries(&self, filter: &WordFilter) -> Result<Vec<DictionaryEntry>, DictionaryError> {
+        let entries = with_connection!(&self.conn, |conn| filter_entries!(filter, self.app_language)
+            .order(dictionary::word)
+            .load::<DbDictionaryEntry>(conn))?;
+
+        Ok(entries.into_iter().map(DictionaryEntry::from).collect())
+    }
+
+    fn count_words(&self, filter: &WordFilter) -> Result<usize, DictionaryError> {
+        let count = with_connection!(&self.conn, |conn| filter_entries!(filter, self.app_language)
+            .count()
+            .get_result::<i64>(conn))?;
+
+        Ok(count as usize)
+    }
+
+    fn find_word(&self, text: &str) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        Ok(self.find_entry(text)?.map(DictionaryEntry::from))
+    }
+
+    /// Insert the word, the unique index on word and language rejects words that already exist
+    fn create_word(&self, word_entry: DictionaryEntry) -> Result<DictionaryEntry, DictionaryError> {
+        let new_word = NewDbDictionaryEntry {
+            word: String::from(&word_entry.word),
+            language: self.app_language.to_string(),
+            role: word_entry.role.to_string()
+        };
+
+        let result = with_connection!(&self.conn, |conn| diesel::insert_into(dictionary::table)
+            .values(&new_word)
+            .execute(conn));
+
+        match result {
+            Ok(_) => Ok(word_entry),
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => Err(DictionaryError::AlreadyExists(word_entry.word)),
+            Err(error) => Err(DictionaryError::from(error))
         }
     }
 
-    fn guessed_word(&self, word_entry: DictionaryEntry) {
-        match diesel::update(dictionary::dsl::dictionary
-            .filter(dictionary::word.eq(word_entry.word)))
+    /// Insert the words in chunks of [CHUNK_SIZE] words, each chunk in a transaction of its own.
+    /// Words given more than once are only inserted once.
+    fn create_words(&self, word_entries: &mut dyn Iterator<Item = DictionaryEntry>) -> Result<CreatedWords, DictionaryError> {
+        let mut created = CreatedWords::default();
+
+        // read once, looking up every word on its own is much slower for large imports
+        let mut seen: HashSet<String> = with_connection!(&self.conn, |conn| dictionary::dsl::dictionary
+            .select(dictionary::word)
             .filter(dictionary::language.eq(&self.app_language.to_string()))
-            .set(dictionary::guessed.eq(true))
-            .execute(&self.conn) {
-                Ok(_) => {},
-                Err(error) => { println!("Error updating the solution.\n{}", error) }
+            .load::<String>(conn))?
+            .into_iter()
+            .collect();
+
+        loop {
+            let chunk: Vec<DictionaryEntry> = (&mut *word_entries).take(CHUNK_SIZE).collect();
+
+            if chunk.is_empty() {
+                return Ok(created);
+            }
+
+            let mut new_words: Vec<NewDbDictionaryEntry> = Vec::with_capacity(chunk.len());
+
+            for word_entry in chunk {
+                if seen.insert(String::from(&word_entry.word)) {
+                    new_words.push(NewDbDictionaryEntry {
+                        word: word_entry.word,
+                        language: self.app_language.to_string(),
+                        role: word_entry.role.to_string()
+                    });
+                } else {
+                    created.skipped += 1;
+                }
+            }
+
+            let inserted = self.insert_chunk(&new_words)?;
+
+            created.inserted += inserted;
+            created.skipped += new_words.len() - inserted;
         }
     }
-}
\ No newline at end of file
+
+    fn guessed_word(&self, word_entry: DictionaryEntry) -> Result<(), DictionaryError> {
+        self.save_state(&word_entry, true, false)
+    }
+
+    fn lost_word(&self, word_entry: DictionaryEntry) -> Result<(), DictionaryError> {
+        self.save_state(&word_entry, false, true)
+    }
+
+    /// Return the guesses the player submitted for the last game of the solution, oldest first
+    fn get_guesses(&self, word_entry: &DictionaryEntry) -> Result<Vec<String>, DictionaryError> {
+        let daily_word = match self.find_last_daily_word(&word_entry.word)? {
+            None => return Ok(Vec::new()),
+            Some(daily_word) => daily_word
+        };
+
+        Ok(with_connection!(&self.conn, |conn| {
+            let query = guesses::dsl::guesses
+                .into_boxed()
+                .filter(guesses::daily_word_id.eq(daily_word.id));
+
+            let query = match self.player_id {
+                Some(player_id) => query.filter(guesses::player_id.eq(player_id)),
+                None => query.filter(guesses::player_id.is_null())
+            };
+
+            query
+                .order(guesses::id)
+                .select(guesses::guess)
+                .load::<String>(conn)
+        })?)
+    }
+
+    /// The day a word has been the word of the day is stored in the used_at column
+    fn records_usage(&self) -> bool {
+        true
+    }
+
+    /// Store a guess submitted for the last game of the solution so the game can be resumed
+    fn save_guess(&self, word_entry: &DictionaryEntry, guess: &str) -> Result<(), DictionaryError> {
+        let daily_word = self.find_last_daily_word(&word_entry.word)?
+            .ok_or_else(|| DictionaryError::NotFound(String::from(&word_entry.word)))?;
+
+        let new_guess = NewDbGuess {
+            dictionary_id: daily_word.dictionary_id,
+            guess: String::from(guess),
+            player_id: self.player_id,
+            daily_word_id: Some(daily_word.id)
+        };
+
+        with_connection!(&self.conn, |conn| diesel::insert_into(guesses::table)
+            .values(&new_guess)
+            .execute(conn))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::result::{DatabaseErrorKind, Error};
+use diesel_migrations::RunMigrationsError;
+
+use crate::db::connection::DbConnection;
+use crate::db::model::DbDictionaryEntry;
+use crate::db::schema::{dictionary, guesses};
+use crate::dictionary::WordRole;
+
+/// A word stored more than once for the same language
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateWord {
+    pub word: String,
+    pub language: String,
+    /// The id of the row the duplicates are merged into
+    pub kept_id: i32,
+    /// The ids of the rows removed by [merge_duplicates]
+    pub removed_ids: Vec<i32>
+}
+
+/// Find the words stored more than once for the same language.
+/// Works on databases the unique index on word and language has not been created for yet,
+/// so it can be used before running the migrations.
+pub fn find_duplicates(conn: &DbConnection) -> Result<Vec<DuplicateWord>, Error> {
+    let entries = with_connection!(conn, |conn| dictionary::dsl::dictionary
+        .order((dictionary::language, dictionary::word, dictionary::id))
+        .load::<DbDictionaryEntry>(conn))?;
+
+    Ok(group_duplicates(entries).iter().map(|group| to_duplicate_word(group)).collect())
+}
+
+/// Merge every word stored more than once for the same language into a single row, in one transaction.
+///
+/// The row of the earliest day the word was used at is kept, the guesses of the other rows are moved
+/// to it, so no guess is lost. The word is marked as guessed or lost if any of the rows was, and
+/// it stays a solution if any of the rows was a solution.
+pub fn merge_duplicates(conn: &DbConnection) -> Result<Vec<DuplicateWord>, Error> {
+    with_connection!(conn, |conn| conn.transaction(|| {
+        let entries = dictionary::dsl::dictionary
+            .order((dictionary::language, dictionary::word, dictionary::id))
+            .load::<DbDictionaryEntry>(conn)?;
+
+        let groups = group_duplicates(entries);
+
+        for group in &groups {
+            let duplicate = to_duplicate_word(group);
+
+            let role = match group.iter().any(|entry| entry.role == WordRole::Solution.to_string()) {
+                true => WordRole::Solution,
+                false => WordRole::Guess
+            };
+
+            diesel::update(guesses::dsl::guesses
+                .filter(guesses::dictionary_id.eq_any(&duplicate.removed_ids)))
+                .set(guesses::dictionary_id.eq(duplicate.kept_id))
+                .execute(conn)?;
+
+            diesel::delete(dictionary::dsl::dictionary
+                .filter(dictionary::id.eq_any(&duplicate.removed_ids)))
+                .execute(conn)?;
+
+            diesel::update(dictionary::dsl::dictionary
+                .filter(dictionary::id.eq(duplicate.kept_id)))
+                .set((
+                    dictionary::guessed.eq(group.iter().any(|entry| entry.guessed)),
+                    dictionary::lost.eq(group.iter().any(|entry| entry.lost)),
+                    dictionary::role.eq(role.to_string())
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(groups.iter().map(|group| to_duplicate_word(group)).collect())
+    }))
+}
+
+/// Check if a migration failed because a unique index can't be created while duplicates exist.
+/// SQLite reports the errors of migrations without their kind, so its message is checked, too.
+pub fn is_unique_violation(error: &RunMigrationsError) -> bool {
+    match error {
+        RunMigrationsError::QueryError(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => true,
+        RunMigrationsError::QueryError(Error::DatabaseError(_, info)) => info.message().starts_with("UNIQUE constraint failed"),
+        _ => false
+    }
+}
+
+/// Group entries ordered by language and word into the words stored more than once.
+/// Every group is ordered by the day the word was used at, unused rows last, then by id.
+fn group_duplicates(entries: Vec<DbDictionaryEntry>) -> Vec<Vec<DbDictionaryEntry>> {
+    let mut groups: Vec<Vec<DbDictionaryEntry>> = Vec::new();
+
+    for entry in entries {
+        match groups.last_mut() {
+            Some(group) if group[0].word == entry.word && group[0].language == entry.language => group.push(entry),
+            _ => groups.push(vec![entry])
+        }
+    }
+
+    groups.retain(|group| group.len() > 1);
+
+    for group in groups.iter_mut() {
+        group.sort_by_key(|entry| (entry.used_at.is_none(), entry.used_at, entry.id));
+    }
+
+    groups
+}
+
+fn to_duplicate_word(group: &[DbDictionaryEntry]) -> DuplicateWord {
+    DuplicateWord {
+        word: String::from(&group[0].word),
+        language: String::from(&group[0].language),
+        kept_id: group[0].id,
+        removed_ids: group[1..].iter().map(|entry| entry.id).collect()
+    }
+}
@@ -0,0 +1,33 @@
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use diesel::result::Error;
+
+use crate::db::connection::DbConnection;
+use crate::db::model::NewDbPlayer;
+use crate::db::schema::players;
+
+/// Return the id of the player with the name given. The player is created if it does not exist yet.
+///
+/// # Arguments
+/// * `conn` - The [DbConnection] to the database the player is stored in
+/// * `name` - A string slice that holds the name of the player
+pub fn get_player_id(conn: &DbConnection, name: &str) -> Result<i32, Error> {
+    let new_player = NewDbPlayer {
+        name: String::from(name)
+    };
+
+    // the unique name keeps two players starting at the same time from creating the player twice
+    match conn {
+        DbConnection::Sqlite(conn) => diesel::insert_or_ignore_into(players::table)
+            .values(&new_player)
+            .execute(conn)?,
+        DbConnection::Postgres(conn) => diesel::insert_into(players::table)
+            .values(&new_player)
+            .on_conflict_do_nothing()
+            .execute(conn)?
+    };
+
+    with_connection!(conn, |conn| players::dsl::players
+        .filter(players::name.eq(name))
+        .select(players::id)
+        .get_result::<i32>(conn))
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+use crate::db::connection::DbConnection;
+use crate::db::db_player::get_player_id;
+use crate::db::model::{DbGameResult, DbPlayer, NewDbGameResult};
+use crate::db::schema::{game_results, players};
+use crate::dictionary::DictionaryError;
+use crate::lang::locale::AppLanguage;
+use crate::statistics::{GameResult, ResultKind, StatisticsStore};
+
+pub struct DbStatisticsStore {
+    conn: DbConnection,
+    app_language: AppLanguage,
+    kind: ResultKind,
+    player_id: Option<i32>
+}
+
+impl DbStatisticsStore {
+    /// Connect to the database given and apply pending migrations, see [DbConnection::establish].
+    ///
+    /// # Arguments
+    /// * `db_url` - A String that holds the url of a PostgreSQL database or the path of a SQLite database
+    /// * `app_language` - The language of the results. See [AppLanguage]
+    /// * `kind` - Only results of this kind are recorded and read. See [ResultKind]
+    pub fn new(db_url: String, app_language: AppLanguage, kind: ResultKind) -> Result<DbStatisticsStore, DictionaryError> {
+        let conn = DbConnection::establish(&db_url)?;
+
+        conn.run_migrations()?;
+
+        Ok(DbStatisticsStore {
+            conn,
+            app_language,
+            kind,
+            player_id: None
+        })
+    }
+
+    /// Record and read the results of the player given. Results recorded before players were
+    /// introduced belong to no one, like in [DbStatisticsStore::get_player_results].
+    /// The player is created if it does not exist yet.
+    ///
+    /// # Arguments
+    /// * `name` - A string slice that holds the name of the player
+    pub fn with_player(mut self, name: &str) -> Result<DbStatisticsStore, DictionaryError> {
+        self.player_id = Some(get_player_id(&self.conn, name)?);
+
+        Ok(self)
+    }
+
+    /// Read the results of every player together with the name of the player, ordered by the day
+    /// they were played at. Results recorded before players were introduced belong to no one and
+    /// are left out.
+    ///
+    /// # Arguments
+    /// * `since` - Only read the results played at this day or later, all results if [None]
+    pub fn get_player_results(&self, since: Option<NaiveDate>) -> Result<Vec<(String, GameResult)>, DictionaryError> {
+        let (players, results) = with_connection!(&self.conn, |conn| players::dsl::players.load::<DbPlayer>(conn).and_then(|players| {
+            let query = game_results::dsl::game_results
+                .into_boxed()
+                .filter(game_results::language.eq(self.app_language.to_string()))
+                .filter(game_results::kind.eq(self.kind.to_string()))
+                .filter(game_results::player_id.is_not_null());
+
+            let query = match since {
+                Some(day) => query.filter(game_results::played_at.ge(day)),
+                None => query
+            };
+
+            query
+                .order((game_results::played_at, game_results::id))
+                .select((game_results::player_id, game_results::played_at, game_results::won, game_results::attempts))
+                .load::<(Option<i32>, NaiveDate, bool, i32)>(conn)
+                .map(|results| (players, results))
+        }))?;
+
+        let names: HashMap<i32, String> = players.into_iter()
+            .map(|player| (player.id, player.name))
+            .collect();
+
+        Ok(results.into_iter()
+            .filter_map(|(player_id, played_at, won, attempts)| Some((
+                names.get(&player_id?)?.clone(),
+                GameResult { played_at, won, attempts: attempts as usize }
+            )))
+            .collect())
+    }
+}
+
+impl StatisticsStore for DbStatisticsStore {
+    fn record_result(&self, result: GameResult) -> Result<(), DictionaryError> {
+        let new_result = NewDbGameResult {
+            played_at: result.played_at,
+            won: result.won,
+            attempts: result.attempts as i32,
+            language: self.app_language.to_string(),
+            kind: self.kind.to_string(),
+            player_id: self.player_id
+        };
+
+        with_connection!(&self.conn, |conn| diesel::insert_into(game_results::table)
+            .values(&new_result)
+            .execute(conn))?;
+
+        Ok(())
+    }
+
+    fn get_results(&self) -> Result<Vec<GameResult>, DictionaryError> {
+        let results = with_connection!(&self.conn, |conn| {
+            let query = game_results::dsl::game_results
+                .into_boxed()
+                .filter(game_results::language.eq(self.app_language.to_string()))
+                .filter(game_results::kind.eq(self.kind.to_string()));
+
+            let query = match self.player_id {
+                Some(player_id) => query.filter(game_results::player_id.eq(player_id)),
+                None => query
+            };
+
+            query
+                .order((game_results::played_at, game_results::id))
+                .select((game_results::played_at, game_results::won, game_results::attempts))
+                .load::<DbGameResult>(conn)
+        })?;
+
+        Ok(results.into_iter().map(|result| GameResult {
+            played_at: result.played_at,
+            won: result.won,
+            attempts: result.attempts as usize
+        }).collect())
+    }
+}
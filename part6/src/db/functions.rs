@@ -0,0 +1,3 @@
+use diesel::sql_types::{Integer, Text};
+
+sql_function!(fn length(x: Text) -> Integer);
@@ -1,3 +1,12 @@
+#[macro_use]
+pub mod connection;
+#[allow(non_local_definitions)]
+mod functions;
+#[allow(non_local_definitions)]
 mod model;
+#[allow(non_local_definitions)]
 mod schema;
-pub mod db_dictionary;
\ No newline at end of file
+pub mod db_dictionary;
+pub mod db_maintenance;
+pub mod db_player;
+pub mod db_statistics;
\ No newline at end of file
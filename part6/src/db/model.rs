@@ -1,6 +1,6 @@
 use chrono::NaiveDate;
 
-use super::schema::dictionary;
+use super::schema::{daily_words, dictionary, game_results, guesses, players, results};
 
 #[derive(Queryable)]
 pub struct DbDictionaryEntry {
@@ -8,12 +8,82 @@ pub struct DbDictionaryEntry {
     pub word: String,
     pub used_at: Option<NaiveDate>,
     pub guessed: bool,
-    pub language: String
+    pub language: String,
+    pub lost: bool,
+    pub role: String
 }
 
 #[derive(Insertable)]
 #[table_name="dictionary"]
 pub struct NewDbDictionaryEntry {
     pub word: String,
-    pub language: String
+    pub language: String,
+    pub role: String
+}
+
+#[derive(Insertable)]
+#[table_name="guesses"]
+pub struct NewDbGuess {
+    pub dictionary_id: i32,
+    pub guess: String,
+    pub player_id: Option<i32>,
+    pub daily_word_id: Option<i32>
+}
+
+#[derive(Queryable)]
+pub struct DbDailyWord {
+    pub id: i32,
+    pub played_at: NaiveDate,
+    pub dictionary_id: i32,
+    pub guessed: bool,
+    pub lost: bool
+}
+
+#[derive(Insertable)]
+#[table_name="daily_words"]
+pub struct NewDbDailyWord {
+    pub played_at: NaiveDate,
+    pub language: String,
+    pub word_len: i32,
+    pub dictionary_id: i32
+}
+
+#[derive(Queryable)]
+pub struct DbGameResult {
+    pub played_at: NaiveDate,
+    pub won: bool,
+    pub attempts: i32
+}
+
+#[derive(Insertable)]
+#[table_name="game_results"]
+pub struct NewDbGameResult {
+    pub played_at: NaiveDate,
+    pub won: bool,
+    pub attempts: i32,
+    pub language: String,
+    pub kind: String,
+    pub player_id: Option<i32>
+}
+
+#[derive(Queryable)]
+pub struct DbPlayer {
+    pub id: i32,
+    pub name: String
+}
+
+#[derive(Insertable)]
+#[table_name="players"]
+pub struct NewDbPlayer {
+    pub name: String
+}
+
+#[derive(Insertable)]
+#[table_name="results"]
+pub struct NewDbResult {
+    pub player_id: i32,
+    pub dictionary_id: i32,
+    pub played_at: NaiveDate,
+    pub guessed: bool,
+    pub lost: bool
 }
\ No newline at end of file
@@ -5,5 +5,70 @@ table! {
         used_at -> Nullable<Date>,
         guessed -> Bool,
         language -> Text,
+        lost -> Bool,
+        role -> Text,
     }
 }
+
+table! {
+    guesses (id) {
+        id -> Integer,
+        dictionary_id -> Integer,
+        guess -> Text,
+        player_id -> Nullable<Integer>,
+        daily_word_id -> Nullable<Integer>,
+    }
+}
+
+table! {
+    game_results (id) {
+        id -> Integer,
+        played_at -> Date,
+        won -> Bool,
+        attempts -> Integer,
+        language -> Text,
+        kind -> Text,
+        player_id -> Nullable<Integer>,
+    }
+}
+
+table! {
+    players (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+table! {
+    results (id) {
+        id -> Integer,
+        player_id -> Integer,
+        dictionary_id -> Integer,
+        played_at -> Date,
+        guessed -> Bool,
+        lost -> Bool,
+    }
+}
+
+table! {
+    daily_words (id) {
+        id -> Integer,
+        played_at -> Date,
+        language -> Text,
+        word_len -> Integer,
+        dictionary_id -> Integer,
+        guessed -> Bool,
+        lost -> Bool,
+    }
+}
+
+joinable!(daily_words -> dictionary (dictionary_id));
+
+allow_tables_to_appear_in_same_query!(
+    daily_words,
+    dictionary,
+    game_results,
+    guesses,
+    players,
+    results,
+);
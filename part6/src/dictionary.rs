@@ -1,33 +1,323 @@
+use crate::db::connection::is_postgres_url;
 use crate::db::db_dictionary::DbDictionary;
 use crate::lang::locale::AppLanguage;
 use crate::text::text_dictionary::TextDictionary;
 
-use std::env;
+use std::{env, error, fmt, io};
+use chrono::NaiveDate;
 use dotenv::dotenv;
 
 /// Provides basic functions for reading and writing from and to a dictionary
 pub trait Dictionary {
-    fn get_random_word(&self) -> Option<DictionaryEntry>;
-    fn find_word(&self, text: &str) -> Option<DictionaryEntry>;
-    fn create_word(&self, word_entry: DictionaryEntry) -> Option<DictionaryEntry>;
-    fn guessed_word(&self, word_entry: DictionaryEntry);
+    /// Return a random word with a length of word_len characters that can be a solution, see [WordRole]
+    fn get_random_word(&self, word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError>;
+    /// Return the solution with a length of word_len characters to guess on the day given.
+    /// Every dictionary with the same words returns the same word for the same day and seed,
+    /// see [crate::game::daily::daily_index].
+    fn get_daily_word(&self, day: NaiveDate, word_len: usize, seed: &str) -> Result<Option<DictionaryEntry>, DictionaryError>;
+    /// Return the days before the day given that had a solution with a length of word_len characters, latest first
+    fn get_archive_days(&self, before: NaiveDate, word_len: usize) -> Result<Vec<NaiveDate>, DictionaryError>;
+    /// Return the solution with a length of word_len characters that was played on the day given
+    fn get_archive_word(&self, day: NaiveDate, word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError>;
+    /// Return every word matching the filter in alphabetical order, see [WordFilter]
+    fn get_entries(&self, filter: &WordFilter) -> Result<Vec<DictionaryEntry>, DictionaryError>;
+    /// Return the number of words matching the filter, see [WordFilter]
+    fn count_words(&self, filter: &WordFilter) -> Result<usize, DictionaryError>;
+    /// Search the dictionary for a word of any [WordRole]
+    fn find_word(&self, text: &str) -> Result<Option<DictionaryEntry>, DictionaryError>;
+    fn create_word(&self, word_entry: DictionaryEntry) -> Result<DictionaryEntry, DictionaryError>;
+    /// Add many words at once. Words that are already part of the dictionary are skipped.
+    /// The default implementation calls [Dictionary::create_word] for every word.
+    fn create_words(&self, word_entries: &mut dyn Iterator<Item = DictionaryEntry>) -> Result<CreatedWords, DictionaryError> {
+        let mut created = CreatedWords::default();
+
+        for word_entry in word_entries {
+            match self.create_word(word_entry) {
+                Ok(_) => created.inserted += 1,
+                Err(DictionaryError::AlreadyExists(_)) => created.skipped += 1,
+                Err(error) => return Err(error)
+            }
+        }
+
+        Ok(created)
+    }
+    fn guessed_word(&self, word_entry: DictionaryEntry) -> Result<(), DictionaryError>;
+    fn lost_word(&self, word_entry: DictionaryEntry) -> Result<(), DictionaryError>;
+    fn get_guesses(&self, word_entry: &DictionaryEntry) -> Result<Vec<String>, DictionaryError>;
+    fn save_guess(&self, word_entry: &DictionaryEntry, guess: &str) -> Result<(), DictionaryError>;
+
+    /// Persist words created with [Dictionary::create_word] that have not been written yet
+    fn flush(&self) -> Result<(), DictionaryError> {
+        Ok(())
+    }
+
+    /// Whether the dictionary remembers which words have been the word of a day.
+    /// Without it, every word counts as unused, see [WordFilter::with_used].
+    fn records_usage(&self) -> bool {
+        false
+    }
 }
 
 /// Represents a dictionary entry
+#[derive(Clone)]
 pub struct  DictionaryEntry {
     pub word: String,
-    pub guessed: bool
+    pub guessed: bool,
+    pub lost: bool,
+    pub role: WordRole
+}
+
+/// The role of a word in the dictionary
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum WordRole {
+    /// The word can be drawn as the word to guess. It is accepted as a guess, too.
+    Solution,
+    /// The word is only accepted as a guess
+    Guess
+}
+
+/// Parse a [WordRole] as written by its Display implementation. Anything unknown is a [WordRole::Solution],
+/// so stored words without a role are solutions. Use [try_parse_word_role] for roles given by the user.
+pub fn parse_word_role(role_str: &str) -> WordRole {
+    match role_str {
+        "guess" => WordRole::Guess,
+        _ => WordRole::Solution
+    }
+}
+
+/// Parse a [WordRole] given by the user: `solution` or `guess`
+pub fn try_parse_word_role(role_str: &str) -> Result<WordRole, String> {
+    match role_str {
+        "solution" => Ok(WordRole::Solution),
+        "guess" => Ok(WordRole::Guess),
+        _ => Err(format!("'{}' is not a valid role, use solution or guess", role_str))
+    }
+}
+
+/// The number of words added and skipped by [Dictionary::create_words]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CreatedWords {
+    pub inserted: usize,
+    /// Words that were already part of the dictionary
+    pub skipped: usize
+}
+
+/// Selects words of a [Dictionary], see [Dictionary::get_entries] and [Dictionary::count_words].
+/// Criteria that are not set match every word.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WordFilter {
+    /// The language of the words, defaults to the language of the dictionary
+    pub language: Option<AppLanguage>,
+    pub word_len: Option<usize>,
+    pub role: Option<WordRole>,
+    /// Select words that have or have not been the word of a day, see [Dictionary::get_daily_word]
+    pub used: Option<bool>,
+    pub guessed: Option<bool>,
+    /// A pattern the words have to match, see [matches_pattern]
+    pub pattern: Option<String>
+}
+
+impl WordFilter {
+    /// Create a filter that matches every word of the dictionary's language
+    pub fn new() -> WordFilter {
+        WordFilter::default()
+    }
+
+    pub fn with_language(mut self, app_language: AppLanguage) -> WordFilter {
+        self.language = Some(app_language);
+
+        self
+    }
+
+    pub fn with_word_len(mut self, word_len: usize) -> WordFilter {
+        self.word_len = Some(word_len);
+
+        self
+    }
+
+    pub fn with_role(mut self, role: WordRole) -> WordFilter {
+        self.role = Some(role);
+
+        self
+    }
+
+    pub fn with_used(mut self, used: bool) -> WordFilter {
+        self.used = Some(used);
+
+        self
+    }
+
+    pub fn with_guessed(mut self, guessed: bool) -> WordFilter {
+        self.guessed = Some(guessed);
+
+        self
+    }
+
+    /// Only words matching the pattern, see [matches_pattern]. Words are stored in lower case, so the
+    /// pattern is lowercased, too. This keeps backends that compare case-sensitively like PostgreSQL
+    /// in line with SQLite, which ignores the case in LIKE.
+    pub fn with_pattern(mut self, pattern: &str) -> WordFilter {
+        self.pattern = Some(pattern.to_lowercase());
+
+        self
+    }
+}
+
+/// Check if the word matches the pattern given.
+/// `_` matches a single character and `*` matches any number of characters, e.g. `r_st*`
+/// matches `rusty` and `rust`. Every other character has to match exactly.
+pub fn matches_pattern(word: &str, pattern: &str) -> bool {
+    let word_characters: Vec<char> = word.chars().collect();
+    let pattern_characters: Vec<char> = pattern.chars().collect();
+
+    matches_characters(&word_characters, &pattern_characters)
+}
+
+fn matches_characters(word: &[char], pattern: &[char]) -> bool {
+    match pattern.split_first() {
+        None => word.is_empty(),
+        Some(('*', rest)) => (0..=word.len()).any(|skipped| matches_characters(&word[skipped..], rest)),
+        Some((expected, rest)) => match word.split_first() {
+            Some((character, word_rest)) if *expected == '_' || expected == character => matches_characters(word_rest, rest),
+            _ => false
+        }
+    }
 }
 
-pub fn get_dictionary(app_language: AppLanguage, dictionary_flag: String) -> Box<dyn Dictionary> {
+/// Errors reported by a [Dictionary]
+#[derive(Debug)]
+pub enum DictionaryError {
+    /// The word is already part of the dictionary
+    AlreadyExists(String),
+    /// The word is not part of the dictionary
+    NotFound(String),
+    /// The dictionary is not configured correctly, e.g. `DATABASE_URL` is not set
+    Configuration(String),
+    /// Reading from or writing to the dictionary or statistics file failed
+    Io(io::Error),
+    /// Connecting to the database failed
+    Connection(diesel::ConnectionError),
+    /// A database query failed
+    Database(diesel::result::Error),
+    /// Migrating the database to the current schema failed
+    Migration(diesel_migrations::RunMigrationsError)
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictionaryError::AlreadyExists(word) => write!(f, "'{}' already exists in the dictionary.", word),
+            DictionaryError::NotFound(word) => write!(f, "'{}' does not exist in the dictionary.", word),
+            DictionaryError::Configuration(message) => write!(f, "{}", message),
+            DictionaryError::Io(error) => write!(f, "Error accessing the file: {}", error),
+            DictionaryError::Connection(error) => write!(f, "Error connecting to the database: {}", error),
+            DictionaryError::Database(error) => write!(f, "Database error: {}", error),
+            DictionaryError::Migration(error) => write!(f, "Error migrating the database: {}", error)
+        }
+    }
+}
+
+impl error::Error for DictionaryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DictionaryError::Io(error) => Some(error),
+            DictionaryError::Connection(error) => Some(error),
+            DictionaryError::Database(error) => Some(error),
+            DictionaryError::Migration(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for DictionaryError {
+    fn from(error: io::Error) -> Self {
+        DictionaryError::Io(error)
+    }
+}
+
+impl From<diesel::ConnectionError> for DictionaryError {
+    fn from(error: diesel::ConnectionError) -> Self {
+        DictionaryError::Connection(error)
+    }
+}
+
+impl From<diesel::result::Error> for DictionaryError {
+    fn from(error: diesel::result::Error) -> Self {
+        DictionaryError::Database(error)
+    }
+}
+
+impl From<diesel_migrations::RunMigrationsError> for DictionaryError {
+    fn from(error: diesel_migrations::RunMigrationsError) -> Self {
+        DictionaryError::Migration(error)
+    }
+}
+
+/// Open the dictionary selected by dictionary_flag.
+///
+/// * `db` - The database at `DATABASE_URL`, PostgreSQL or SQLite depending on the url
+/// * `postgres` - The PostgreSQL database at `DATABASE_URL`
+/// * anything else - The text file `res/dictionary_<language>.txt`
+pub fn get_dictionary(app_language: AppLanguage, dictionary_flag: String) -> Result<Box<dyn Dictionary>, DictionaryError> {
+    match dictionary_flag.as_str() {
+        "db" | "postgres" => Ok(Box::new(DbDictionary::new(get_database_url(&dictionary_flag)?, app_language)?)),
+        _ => Ok(Box::new(TextDictionary::new(format!("res/dictionary_{}.txt", app_language.to_string().to_lowercase()))
+            .with_language(app_language))),
+    }
+}
+
+/// Open the dictionary selected by dictionary_flag for the player given, see [get_dictionary].
+/// The database dictionaries keep the games of every player apart, see [DbDictionary::with_player].
+/// The text dictionary is not shared, so the player is ignored.
+pub fn get_player_dictionary(app_language: AppLanguage, dictionary_flag: String, player_name: &str) -> Result<Box<dyn Dictionary>, DictionaryError> {
     match dictionary_flag.as_str() {
-        "db" =>  {
-            dotenv().ok();
-            Box::new(DbDictionary::new(
-                env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
-                app_language
-            ))
-        },
-        _ => Box::new(TextDictionary::new(format!("res/dictionary_{}.txt", app_language.to_string().to_lowercase()))),
+        "db" | "postgres" => Ok(Box::new(DbDictionary::new(get_database_url(&dictionary_flag)?, app_language)?
+            .with_player(player_name)?)),
+        _ => get_dictionary(app_language, dictionary_flag)
+    }
+}
+
+/// Read `DATABASE_URL` and check it matches the dictionary selected by dictionary_flag
+pub(crate) fn get_database_url(dictionary_flag: &str) -> Result<String, DictionaryError> {
+    dotenv().ok();
+
+    let db_url = env::var("DATABASE_URL")
+        .map_err(|_| DictionaryError::Configuration(String::from("DATABASE_URL must be set")))?;
+
+    if dictionary_flag == "postgres" && !is_postgres_url(&db_url) {
+        return Err(DictionaryError::Configuration(String::from("DATABASE_URL must start with postgres:// to use the postgres dictionary")));
     }
+
+    Ok(db_url)
+}
+
+#[cfg(test)]
+#[test]
+fn test_matches_pattern() {
+    assert!(matches_pattern("rusty", "rusty"));
+    assert!(matches_pattern("rusty", "r_st_"));
+    assert!(matches_pattern("rusty", "r*"));
+    assert!(matches_pattern("rusty", "*y"));
+    assert!(matches_pattern("rusty", "*s*"));
+    assert!(matches_pattern("rust", "r_st*"));
+    assert!(matches_pattern("", "*"));
+
+    assert!(!matches_pattern("rusty", "r_st"));
+    assert!(!matches_pattern("rusty", "lusty"));
+    assert!(!matches_pattern("rusty", "*x*"));
+    assert!(!matches_pattern("rust", "rust_"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_word_role() {
+    assert_eq!(parse_word_role("guess"), WordRole::Guess);
+    assert_eq!(parse_word_role(""), WordRole::Solution);
+
+    assert_eq!(try_parse_word_role("solution"), Ok(WordRole::Solution));
+    assert_eq!(try_parse_word_role("guess"), Ok(WordRole::Guess));
+    assert!(try_parse_word_role("gues").is_err());
+    assert!(try_parse_word_role("").is_err());
 }
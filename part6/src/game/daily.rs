@@ -0,0 +1,197 @@
+use std::env;
+
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, Utc};
+use chrono_tz::Tz;
+use dotenv::dotenv;
+
+use crate::lang::locale::AppLanguage;
+
+/// Decides at which time of the day the next puzzle starts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DayBoundary {
+    /// Midnight in the timezone of the machine
+    Local,
+    /// Midnight UTC
+    Utc,
+    /// Midnight at a fixed offset from UTC, e.g. `+02:00`
+    Offset(FixedOffset),
+    /// Midnight in a named timezone, e.g. `Europe/Vienna`, including daylight saving time
+    Zone(Tz)
+}
+
+impl DayBoundary {
+    /// The puzzle day at this moment
+    pub fn today(&self) -> NaiveDate {
+        self.day_of(Utc::now())
+    }
+
+    /// The puzzle day at the moment given
+    pub fn day_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        match self {
+            DayBoundary::Local => instant.with_timezone(&Local).date_naive(),
+            DayBoundary::Utc => instant.date_naive(),
+            DayBoundary::Offset(offset) => instant.with_timezone(offset).date_naive(),
+            DayBoundary::Zone(zone) => instant.with_timezone(zone).date_naive()
+        }
+    }
+}
+
+/// Parse a [DayBoundary]: `local`, `utc`, an offset like `+02:00` or `-05:00`, or the name of a timezone like `Europe/Vienna`
+pub fn parse_day_boundary(boundary_str: &str) -> Result<DayBoundary, String> {
+    match boundary_str {
+        "local" => Ok(DayBoundary::Local),
+        "utc" | "UTC" => Ok(DayBoundary::Utc),
+        _ if boundary_str.starts_with('+') || boundary_str.starts_with('-') => boundary_str.parse::<FixedOffset>()
+            .map(DayBoundary::Offset)
+            .map_err(|_| format!("'{}' is not a valid offset, use e.g. +02:00", boundary_str)),
+        _ => boundary_str.parse::<Tz>()
+            .map(DayBoundary::Zone)
+            .map_err(|_| format!("'{}' is not a known timezone, use local, utc, an offset like +02:00 or a name like Europe/Vienna", boundary_str))
+    }
+}
+
+/// Return the [DayBoundary] given by timezone_flag. If it is not set, `WORDLE_TIMEZONE` is used, defaulting to UTC.
+pub fn get_day_boundary(timezone_flag: Option<String>) -> Result<DayBoundary, String> {
+    dotenv().ok();
+
+    match timezone_flag.or_else(|| env::var("WORDLE_TIMEZONE").ok()) {
+        None => Ok(DayBoundary::Utc),
+        Some(flag) => parse_day_boundary(&flag)
+    }
+}
+
+/// Return the index of the word of the day within a list of word_count words.
+///
+/// The list is expected to be sorted, so everyone using the same dictionary, language and seed
+/// gets the same word. Consecutive days walk through a permutation of the list derived from the
+/// language and the seed, so no word is repeated before every word has been used once.
+/// Returns [None] if the list is empty.
+///
+/// # Arguments
+/// * `day` - The day to select the word for
+/// * `app_language` - The language of the dictionary. See [AppLanguage]
+/// * `seed` - A string slice that holds a seed shared by all players, changing it changes every word
+/// * `word_count` - The number of words to select from
+pub fn daily_index(day: NaiveDate, app_language: AppLanguage, seed: &str, word_count: usize) -> Option<usize> {
+    if word_count == 0 {
+        return None;
+    }
+
+    let hash = fnv1a(format!("{}:{}", app_language, seed).as_bytes());
+    let count = word_count as u64;
+
+    // the step has to be coprime with the number of words to visit every index
+    let mut step = (hash % count).max(1);
+    while gcd(step, count) != 1 {
+        step += 1;
+    }
+    let offset = (hash >> 32) % count;
+
+    let day_number = day.num_days_from_ce() as u128;
+
+    Some(((step as u128 * day_number + offset as u128) % count as u128) as usize)
+}
+
+/// 64 bit FNV-1a, used instead of [std::collections::hash_map::DefaultHasher]
+/// because its result must not change between Rust versions or machines
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+use std::collections::HashSet;
+
+#[cfg(test)]
+#[test]
+fn test_daily_index_is_stable() {
+    let day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+
+    assert_eq!(daily_index(day, AppLanguage::EN, "", 0), None);
+    assert_eq!(daily_index(day, AppLanguage::EN, "", 1), Some(0));
+
+    // fixed values, a different result breaks the daily word for everyone who updates
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(daily_index(day, AppLanguage::EN, "", 2315), daily_index(day, AppLanguage::EN, "", 2315));
+    assert!(daily_index(day, AppLanguage::EN, "", 2315).unwrap() < 2315);
+}
+
+#[cfg(test)]
+#[test]
+fn test_daily_index_visits_every_word() {
+    let first_day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+
+    for word_count in [1, 2, 10, 64, 2315] {
+        let indices: HashSet<usize> = first_day.iter_days()
+            .take(word_count)
+            .map(|day| daily_index(day, AppLanguage::DE, "team", word_count).unwrap())
+            .collect();
+
+        assert_eq!(indices.len(), word_count);
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_daily_index_depends_on_seed_and_language() {
+    let first_day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let sequence = |app_language: AppLanguage, seed: &str| -> Vec<usize> {
+        first_day.iter_days()
+            .take(10)
+            .map(|day| daily_index(day, app_language, seed, 2315).unwrap())
+            .collect()
+    };
+
+    assert_eq!(sequence(AppLanguage::EN, "team"), sequence(AppLanguage::EN, "team"));
+    assert_ne!(sequence(AppLanguage::EN, "team"), sequence(AppLanguage::EN, "other team"));
+    assert_ne!(sequence(AppLanguage::EN, "team"), sequence(AppLanguage::DE, "team"));
+}
+
+#[cfg(test)]
+fn instant(text: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(text).unwrap().with_timezone(&Utc)
+}
+
+#[cfg(test)]
+#[test]
+fn test_day_of() {
+    let day = |month: u32, day: u32| NaiveDate::from_ymd_opt(2022, month, day).unwrap();
+    let vienna = DayBoundary::Zone(chrono_tz::Europe::Vienna);
+    let new_york = DayBoundary::Zone(chrono_tz::America::New_York);
+
+    // summer, Vienna is two hours ahead of UTC
+    assert_eq!(DayBoundary::Utc.day_of(instant("2022-07-01T22:30:00Z")), day(7, 1));
+    assert_eq!(vienna.day_of(instant("2022-07-01T21:30:00Z")), day(7, 1));
+    assert_eq!(vienna.day_of(instant("2022-07-01T22:30:00Z")), day(7, 2));
+    assert_eq!(new_york.day_of(instant("2022-07-02T03:30:00Z")), day(7, 1));
+    assert_eq!(new_york.day_of(instant("2022-07-02T04:30:00Z")), day(7, 2));
+
+    // winter, Vienna is one hour ahead of UTC
+    assert_eq!(vienna.day_of(instant("2022-01-01T22:30:00Z")), day(1, 1));
+    assert_eq!(vienna.day_of(instant("2022-01-01T23:30:00Z")), day(1, 2));
+
+    let offset = DayBoundary::Offset(FixedOffset::east_opt(2 * 3600).unwrap());
+    assert_eq!(offset.day_of(instant("2022-01-01T21:30:00Z")), day(1, 1));
+    assert_eq!(offset.day_of(instant("2022-01-01T22:30:00Z")), day(1, 2));
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_day_boundary() {
+    assert_eq!(parse_day_boundary("local"), Ok(DayBoundary::Local));
+    assert_eq!(parse_day_boundary("utc"), Ok(DayBoundary::Utc));
+    assert_eq!(parse_day_boundary("UTC"), Ok(DayBoundary::Utc));
+    assert_eq!(parse_day_boundary("+02:00"), Ok(DayBoundary::Offset(FixedOffset::east_opt(2 * 3600).unwrap())));
+    assert_eq!(parse_day_boundary("-05:30"), Ok(DayBoundary::Offset(FixedOffset::west_opt(5 * 3600 + 1800).unwrap())));
+    assert_eq!(parse_day_boundary("Europe/Vienna"), Ok(DayBoundary::Zone(chrono_tz::Europe::Vienna)));
+
+    assert!(parse_day_boundary("+25:00").is_err());
+    assert!(parse_day_boundary("Europe/Nowhere").is_err());
+}
@@ -0,0 +1,200 @@
+use crate::dictionary::{Dictionary, DictionaryEntry, DictionaryError};
+use crate::game::feedback::GuessFeedback;
+use crate::game::hard_mode::{check_hard_mode, HardModeViolation};
+
+/// The state of a running [Game]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameState {
+    InProgress,
+    Won,
+    Lost
+}
+
+/// Reasons why a guess was rejected by the [Game]
+#[derive(Debug)]
+pub enum GuessError {
+    InvalidLength { expected: usize, actual: usize },
+    NotInWordList,
+    HardMode(HardModeViolation),
+    GameOver,
+    /// The guess could not be looked up in the [Dictionary]
+    Dictionary(DictionaryError)
+}
+
+/// A wordle game played against a single solution.
+/// The game does not read from or write to the terminal, frontends are expected to feed the
+/// player's input and render the results returned.
+pub struct Game {
+    solution: DictionaryEntry,
+    max_attempts: usize,
+    guesses: Vec<GuessFeedback>,
+    state: GameState,
+    hard_mode: bool
+}
+
+impl Game {
+    /// Creates a new game for the solution given. If the solution has already been guessed,
+    /// the game starts in [GameState::Won], if the player has already lost, it starts in [GameState::Lost].
+    ///
+    /// # Arguments
+    /// * `solution` - The [DictionaryEntry] the player has to guess
+    /// * `max_attempts` - The number of guesses the player has
+    pub fn new(solution: DictionaryEntry, max_attempts: usize) -> Game {
+        let state = if solution.guessed {
+            GameState::Won
+        } else if solution.lost {
+            GameState::Lost
+        } else {
+            GameState::InProgress
+        };
+
+        Game {
+            solution,
+            max_attempts,
+            guesses: Vec::new(),
+            state,
+            hard_mode: false
+        }
+    }
+
+    /// Enable or disable hard mode. In hard mode, every hint revealed by a previous guess
+    /// has to be used in subsequent guesses, see [check_hard_mode].
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Game {
+        self.hard_mode = hard_mode;
+
+        self
+    }
+
+    /// Restore guesses submitted in a previous session. The guesses are not validated again.
+    /// If the game is still in progress, the state is updated as if the guesses were submitted.
+    ///
+    /// # Arguments
+    /// * `guesses` - The previously submitted guesses, oldest first
+    pub fn with_guesses(mut self, guesses: &[String]) -> Game {
+        for guess in guesses {
+            let feedback = GuessFeedback::new(&self.solution.word, guess);
+
+            self.guesses.push(feedback);
+            self.update_state();
+        }
+
+        self
+    }
+
+    /// Submit a guess. The guess is expected to be lowercase and polished with
+    /// [crate::lang::locale::replace_unicode].
+    /// Rejected guesses do not count as an attempt.
+    ///
+    /// # Arguments
+    /// * `guess` - A string slice that holds the guessed word
+    /// * `dictionary` - The [Dictionary] the guess has to be part of
+    pub fn submit_guess(&mut self, guess: &str, dictionary: &dyn Dictionary) -> Result<GuessFeedback, GuessError> {
+        if self.state != GameState::InProgress {
+            return Err(GuessError::GameOver);
+        }
+
+        if !validate_user_input(guess, self.word_len()) {
+            return Err(GuessError::InvalidLength { expected: self.word_len(), actual: guess.len() });
+        }
+
+        if dictionary.find_word(guess).map_err(GuessError::Dictionary)?.is_none() {
+            return Err(GuessError::NotInWordList);
+        }
+
+        if self.hard_mode {
+            check_hard_mode(&self.guesses, guess).map_err(GuessError::HardMode)?;
+        }
+
+        let feedback = GuessFeedback::new(&self.solution.word, guess);
+
+        self.guesses.push(feedback.clone());
+        self.update_state();
+
+        Ok(feedback)
+    }
+
+    fn update_state(&mut self) {
+        if self.state != GameState::InProgress {
+            return;
+        }
+
+        if self.guesses.last().map(|feedback| feedback.is_solved()).unwrap_or(false) {
+            self.state = GameState::Won;
+        } else if self.remaining_attempts() == 0 {
+            self.state = GameState::Lost;
+        }
+    }
+
+    pub fn solution(&self) -> &DictionaryEntry {
+        &self.solution
+    }
+
+    pub fn word_len(&self) -> usize {
+        self.solution.word.len()
+    }
+
+    /// The feedback of every accepted guess, oldest first
+    pub fn guesses(&self) -> &Vec<GuessFeedback> {
+        &self.guesses
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    pub fn remaining_attempts(&self) -> usize {
+        self.max_attempts.saturating_sub(self.guesses.len())
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.state == GameState::Won
+    }
+
+    pub fn is_lost(&self) -> bool {
+        self.state == GameState::Lost
+    }
+}
+
+/// Check if the user input has the expected length
+pub fn validate_user_input(user_input: &str, expected_len: usize) -> bool {
+    user_input.len() == expected_len
+}
+
+#[cfg(test)]
+use crate::lang::locale::{AppLanguage, replace_unicode};
+
+#[cfg(test)]
+#[test]
+fn test_validate_user_input() {
+    assert!(validate_user_input(
+        replace_unicode("schön", AppLanguage::DE).as_str(), 6
+    ));
+
+    assert!(validate_user_input(
+        replace_unicode("schön", AppLanguage::EN).as_str(), 5
+    ));
+
+    assert!(validate_user_input(
+        replace_unicode("lüge", AppLanguage::DE).as_str(), 5
+    ));
+
+    assert!(validate_user_input(
+        replace_unicode("lüge", AppLanguage::EN).as_str(), 4
+    ));
+
+    assert!(validate_user_input(
+        replace_unicode("howdy", AppLanguage::DE).as_str(), 5
+    ));
+
+    assert!(validate_user_input(
+        replace_unicode("howdy", AppLanguage::EN).as_str(), 5
+    ));
+}
@@ -0,0 +1,55 @@
+use crate::game::scoring::{LetterStatus, score};
+
+/// A single letter of a guess together with its [LetterStatus]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LetterFeedback {
+    pub letter: char,
+    pub status: LetterStatus
+}
+
+/// The result of scoring a guess against the solution.
+/// Frontends decide how to present it, see [crate::game::render].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuessFeedback {
+    pub letters: Vec<LetterFeedback>
+}
+
+impl GuessFeedback {
+    /// Score the guessed word against the solution word using [score]
+    ///
+    /// # Arguments
+    /// * `solution_word` - A string slice that holds the word to guess
+    /// * `guessed_word` - A string slice that holds the guess
+    pub fn new(solution_word: &str, guessed_word: &str) -> GuessFeedback {
+        GuessFeedback {
+            letters: guessed_word.chars()
+                .zip(score(solution_word, guessed_word))
+                .map(|(letter, status)| LetterFeedback { letter, status })
+                .collect()
+        }
+    }
+
+    /// The guessed word
+    pub fn word(&self) -> String {
+        self.letters.iter().map(|letter| letter.letter).collect()
+    }
+
+    /// Returns true if every letter is at the correct position
+    pub fn is_solved(&self) -> bool {
+        !self.letters.is_empty() && self.letters.iter().all(|letter| letter.status == LetterStatus::Correct)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_guess_feedback() {
+    let feedback = GuessFeedback::new("those", "geese");
+
+    assert_eq!(feedback.word(), "geese");
+    assert!(!feedback.is_solved());
+    assert_eq!(feedback.letters[3], LetterFeedback { letter: 's', status: LetterStatus::Correct });
+    assert_eq!(feedback.letters[1], LetterFeedback { letter: 'e', status: LetterStatus::Absent });
+
+    assert!(GuessFeedback::new("those", "those").is_solved());
+    assert!(!GuessFeedback::new("", "").is_solved());
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+
+use crate::game::feedback::GuessFeedback;
+use crate::game::scoring::LetterStatus;
+
+/// A hint revealed by a previous guess that has not been used in hard mode
+#[derive(Clone, Debug, PartialEq)]
+pub enum HardModeViolation {
+    /// A correct letter was not reused at the same position. The position starts at 1.
+    MissingCorrect { position: usize, letter: char },
+    /// A present letter does not appear often enough in the guess.
+    MissingPresent { letter: char, count: usize }
+}
+
+impl fmt::Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardModeViolation::MissingCorrect { position, letter } =>
+                write!(f, "Letter {} must be '{}'.", position, letter),
+            HardModeViolation::MissingPresent { letter, count: 1 } =>
+                write!(f, "Your guess must contain '{}'.", letter),
+            HardModeViolation::MissingPresent { letter, count } =>
+                write!(f, "Your guess must contain '{}' {} times.", letter, count)
+        }
+    }
+}
+
+/// Check a guess against the hints revealed by previous guesses:
+/// every correct letter has to be reused at the same position and every present letter
+/// has to appear in the guess, at least as often as it was revealed by a single guess.
+///
+/// # Arguments
+/// * `previous_guesses` - The feedback of all previous guesses
+/// * `guessed_word` - A string slice that holds the new guess
+pub fn check_hard_mode(previous_guesses: &[GuessFeedback], guessed_word: &str) -> Result<(), HardModeViolation> {
+    let guessed_characters: Vec<char> = guessed_word.chars().collect();
+
+    for feedback in previous_guesses {
+        for (i, letter) in feedback.letters.iter().enumerate() {
+            if letter.status == LetterStatus::Correct && guessed_characters.get(i) != Some(&letter.letter) {
+                return Err(HardModeViolation::MissingCorrect { position: i + 1, letter: letter.letter });
+            }
+        }
+    }
+
+    for feedback in previous_guesses {
+        let mut required: Vec<(char, usize)> = Vec::new();
+
+        for letter in feedback.letters.iter().filter(|letter| letter.status != LetterStatus::Absent) {
+            match required.iter_mut().find(|(character, _)| *character == letter.letter) {
+                Some((_, count)) => *count += 1,
+                None => required.push((letter.letter, 1))
+            }
+        }
+
+        for (letter, count) in required {
+            if guessed_characters.iter().filter(|character| **character == letter).count() < count {
+                return Err(HardModeViolation::MissingPresent { letter, count });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn test_check_hard_mode() {
+    let previous = vec![GuessFeedback::new("rusty", "tours")];
+
+    assert_eq!(check_hard_mode(&previous, "rusty"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "trust"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "fishy"), Err(HardModeViolation::MissingPresent { letter: 't', count: 1 }));
+    assert_eq!(check_hard_mode(&[], "fishy"), Ok(()));
+
+    let previous = vec![GuessFeedback::new("rusty", "fishy")];
+
+    assert_eq!(check_hard_mode(&previous, "busty"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "satyr"), Err(HardModeViolation::MissingCorrect { position: 3, letter: 's' }));
+    assert_eq!(check_hard_mode(&previous, "mossy"), Ok(()));
+}
+
+#[cfg(test)]
+#[test]
+fn test_check_hard_mode_repeated_letters() {
+    // both 's' of "asset" were revealed, so two of them are required
+    let previous = vec![GuessFeedback::new("sassy", "asset")];
+
+    assert_eq!(check_hard_mode(&previous, "sassy"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "bassy"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "brash"), Err(HardModeViolation::MissingCorrect { position: 3, letter: 's' }));
+    assert_eq!(check_hard_mode(&previous, "oasis"), Ok(()));
+    assert_eq!(check_hard_mode(&previous, "basil"), Err(HardModeViolation::MissingPresent { letter: 's', count: 2 }));
+
+    // the second 'e' of "geese" is absent and does not require anything
+    let previous = vec![GuessFeedback::new("those", "geese")];
+
+    assert_eq!(check_hard_mode(&previous, "those"), Ok(()));
+}
+
+#[cfg(test)]
+#[test]
+fn test_hard_mode_violation_display() {
+    assert_eq!(HardModeViolation::MissingCorrect { position: 2, letter: 'u' }.to_string(), "Letter 2 must be 'u'.");
+    assert_eq!(HardModeViolation::MissingPresent { letter: 's', count: 1 }.to_string(), "Your guess must contain 's'.");
+    assert_eq!(HardModeViolation::MissingPresent { letter: 's', count: 2 }.to_string(), "Your guess must contain 's' 2 times.");
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::game::feedback::GuessFeedback;
+use crate::game::scoring::LetterStatus;
+use crate::lang::locale::AppLanguage;
+
+/// A key of the on-screen keyboard and the best known [LetterStatus] of its letter.
+/// The status is [None] if the letter has not been guessed yet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyboardKey {
+    pub letter: char,
+    pub status: Option<LetterStatus>
+}
+
+/// The rows of the keyboard layout used in the country of the language given:
+/// QWERTZ for german, AZERTY for french, QWERTY otherwise.
+pub fn keyboard_layout(app_language: AppLanguage) -> Vec<&'static str> {
+    match app_language {
+        AppLanguage::DE => vec!["qwertzuiop", "asdfghjkl", "yxcvbnm"],
+        AppLanguage::FR => vec!["azertyuiop", "qsdfghjklm", "wxcvbn"],
+        _ => vec!["qwertyuiop", "asdfghjkl", "zxcvbnm"]
+    }
+}
+
+/// Aggregate the best known status of every guessed letter.
+/// [LetterStatus::Correct] beats [LetterStatus::Present], which beats [LetterStatus::Absent].
+///
+/// # Arguments
+/// * `guesses` - The feedback of all guesses so far
+pub fn letter_states(guesses: &[GuessFeedback]) -> HashMap<char, LetterStatus> {
+    let mut states: HashMap<char, LetterStatus> = HashMap::new();
+
+    for letter in guesses.iter().flat_map(|feedback| feedback.letters.iter()) {
+        let state = states.entry(letter.letter).or_insert(letter.status);
+
+        if rank(letter.status) > rank(*state) {
+            *state = letter.status;
+        }
+    }
+
+    states
+}
+
+/// Build the keyboard for the language given with the best known status of every letter
+///
+/// # Arguments
+/// * `app_language` - Selects the keyboard layout, see [keyboard_layout]
+/// * `guesses` - The feedback of all guesses so far
+pub fn keyboard(app_language: AppLanguage, guesses: &[GuessFeedback]) -> Vec<Vec<KeyboardKey>> {
+    let states = letter_states(guesses);
+
+    keyboard_layout(app_language).iter().map(|row| row.chars().map(|letter| KeyboardKey {
+        letter,
+        status: states.get(&letter).copied()
+    }).collect()).collect()
+}
+
+fn rank(status: LetterStatus) -> u8 {
+    match status {
+        LetterStatus::Absent => 0,
+        LetterStatus::Present => 1,
+        LetterStatus::Correct => 2
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_letter_states() {
+    let guesses = vec![
+        GuessFeedback::new("rusty", "tours"),
+        GuessFeedback::new("rusty", "trust"),
+        GuessFeedback::new("rusty", "fishy")
+    ];
+
+    let states = letter_states(&guesses);
+
+    assert_eq!(states.get(&'t'), Some(&LetterStatus::Present));
+    assert_eq!(states.get(&'o'), Some(&LetterStatus::Absent));
+    assert_eq!(states.get(&'u'), Some(&LetterStatus::Present));
+    assert_eq!(states.get(&'s'), Some(&LetterStatus::Correct));
+    assert_eq!(states.get(&'y'), Some(&LetterStatus::Correct));
+    assert_eq!(states.get(&'r'), Some(&LetterStatus::Present));
+    assert_eq!(states.get(&'a'), None);
+
+    // the second 'e' is absent, but the letter itself is correct
+    let states = letter_states(&[GuessFeedback::new("those", "geese")]);
+
+    assert_eq!(states.get(&'e'), Some(&LetterStatus::Correct));
+}
+
+#[cfg(test)]
+#[test]
+fn test_keyboard() {
+    let keys = keyboard(AppLanguage::DE, &[GuessFeedback::new("zebra", "zynes")]);
+
+    assert_eq!(keys.len(), 3);
+    assert_eq!(keys[0][5], KeyboardKey { letter: 'z', status: Some(LetterStatus::Correct) });
+    assert_eq!(keys[2][0], KeyboardKey { letter: 'y', status: Some(LetterStatus::Absent) });
+    assert_eq!(keys[0][2], KeyboardKey { letter: 'e', status: Some(LetterStatus::Present) });
+    assert_eq!(keys[1][0], KeyboardKey { letter: 'a', status: None });
+
+    let keys = keyboard(AppLanguage::EN, &[]);
+
+    assert_eq!(keys[0][5], KeyboardKey { letter: 'y', status: None });
+    assert_eq!(keys[2][0], KeyboardKey { letter: 'z', status: None });
+
+    let keys = keyboard(AppLanguage::FR, &[]);
+
+    assert_eq!(keys[0][0], KeyboardKey { letter: 'a', status: None });
+    assert_eq!(keys[1][9], KeyboardKey { letter: 'm', status: None });
+    assert_eq!(keys.iter().map(|row| row.len()).sum::<usize>(), 26);
+}
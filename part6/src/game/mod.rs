@@ -0,0 +1,9 @@
+pub mod daily;
+pub mod engine;
+pub mod feedback;
+pub mod hard_mode;
+pub mod keyboard;
+pub mod render;
+pub mod scoring;
+pub mod share;
+pub mod solver;
\ No newline at end of file
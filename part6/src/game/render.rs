@@ -0,0 +1,154 @@
+use console::style;
+
+use crate::game::feedback::GuessFeedback;
+use crate::game::keyboard::KeyboardKey;
+use crate::game::scoring::LetterStatus;
+
+/// Turns a [GuessFeedback] or the on-screen keyboard into something a frontend can print
+pub trait FeedbackRenderer {
+    fn render(&self, feedback: &GuessFeedback) -> String;
+    fn render_keyboard(&self, keyboard: &[Vec<KeyboardKey>]) -> String;
+}
+
+/// Colors letters using ANSI escape codes: green for correct letters, yellow for present ones.
+/// Absent letters are dimmed on the keyboard.
+pub struct AnsiRenderer;
+
+/// Renders without colors for colour-blind players and dumb terminals:
+/// correct letters are uppercase in brackets, present letters uppercase in parentheses.
+/// Absent letters are hidden on the keyboard.
+pub struct PlainRenderer;
+
+/// Renders a single line JSON object for bots and scripts.
+pub struct JsonRenderer;
+
+impl FeedbackRenderer for AnsiRenderer {
+    fn render(&self, feedback: &GuessFeedback) -> String {
+        feedback.letters.iter().map(|letter| match letter.status {
+            LetterStatus::Correct => format!("{} ", style(letter.letter).green()),
+            LetterStatus::Present => format!("{} ", style(letter.letter).yellow()),
+            LetterStatus::Absent => format!("{} ", letter.letter)
+        }).collect()
+    }
+
+    fn render_keyboard(&self, keyboard: &[Vec<KeyboardKey>]) -> String {
+        keyboard.iter().enumerate().map(|(i, row)| {
+            let keys: String = row.iter().map(|key| match key.status {
+                Some(LetterStatus::Correct) => format!("{} ", style(key.letter).green()),
+                Some(LetterStatus::Present) => format!("{} ", style(key.letter).yellow()),
+                Some(LetterStatus::Absent) => format!("{} ", style(key.letter).dim()),
+                None => format!("{} ", key.letter)
+            }).collect();
+
+            format!("{}{}", " ".repeat(i), keys)
+        }).collect::<Vec<String>>().join("\n")
+    }
+}
+
+impl FeedbackRenderer for PlainRenderer {
+    fn render(&self, feedback: &GuessFeedback) -> String {
+        feedback.letters.iter().map(|letter| match letter.status {
+            LetterStatus::Correct => format!("[{}]", letter.letter.to_ascii_uppercase()),
+            LetterStatus::Present => format!("({})", letter.letter.to_ascii_uppercase()),
+            LetterStatus::Absent => format!(" {} ", letter.letter)
+        }).collect::<Vec<String>>().join(" ")
+    }
+
+    fn render_keyboard(&self, keyboard: &[Vec<KeyboardKey>]) -> String {
+        keyboard.iter().enumerate().map(|(i, row)| {
+            let keys: Vec<String> = row.iter().map(|key| match key.status {
+                Some(LetterStatus::Correct) => format!("[{}]", key.letter.to_ascii_uppercase()),
+                Some(LetterStatus::Present) => format!("({})", key.letter.to_ascii_uppercase()),
+                Some(LetterStatus::Absent) => String::from("   "),
+                None => format!(" {} ", key.letter)
+            }).collect();
+
+            format!("{}{}", "  ".repeat(i), keys.join(" ")).trim_end().to_string()
+        }).collect::<Vec<String>>().join("\n")
+    }
+}
+
+impl FeedbackRenderer for JsonRenderer {
+    fn render(&self, feedback: &GuessFeedback) -> String {
+        let letters: Vec<String> = feedback.letters.iter().map(|letter| format!(
+            r#"{{"letter":"{}","status":"{}"}}"#, escape_json(&letter.letter.to_string()), letter.status
+        )).collect();
+
+        format!(
+            r#"{{"guess":"{}","solved":{},"letters":[{}]}}"#,
+            escape_json(&feedback.word()), feedback.is_solved(), letters.join(",")
+        )
+    }
+
+    fn render_keyboard(&self, keyboard: &[Vec<KeyboardKey>]) -> String {
+        let keys: Vec<String> = keyboard.iter().flatten()
+            .filter_map(|key| key.status.map(|status| format!(
+                r#""{}":"{}""#, escape_json(&key.letter.to_string()), status
+            )))
+            .collect();
+
+        format!(r#"{{"keyboard":{{{}}}}}"#, keys.join(","))
+    }
+}
+
+/// Escape quotes, backslashes and control characters of a JSON string value
+pub(crate) fn escape_json(value: &str) -> String {
+    value.chars().map(|character| match character {
+        '"' => String::from("\\\""),
+        '\\' => String::from("\\\\"),
+        c if c.is_control() => format!("\\u{:04x}", c as u32),
+        c => c.to_string()
+    }).collect()
+}
+
+pub fn get_renderer(output_flag: String) -> Box<dyn FeedbackRenderer> {
+    match output_flag.as_str() {
+        "plain" => Box::new(PlainRenderer),
+        "json" => Box::new(JsonRenderer),
+        _ => Box::new(AnsiRenderer)
+    }
+}
+
+#[cfg(test)]
+use crate::game::keyboard::keyboard;
+#[cfg(test)]
+use crate::lang::locale::AppLanguage;
+
+#[cfg(test)]
+#[test]
+fn test_plain_renderer() {
+    assert_eq!(PlainRenderer.render(&GuessFeedback::new("those", "geese")), " g   e   e  [S] [E]");
+    assert_eq!(PlainRenderer.render(&GuessFeedback::new("rusty", "tours")), "(T)  o  (U) (R) (S)");
+}
+
+#[cfg(test)]
+#[test]
+fn test_plain_keyboard() {
+    assert_eq!(
+        PlainRenderer.render_keyboard(&keyboard(AppLanguage::EN, &[GuessFeedback::new("rusty", "tours")])),
+        concat!(
+            " q   w   e  (R) (T)  y  (U)  i       p\n",
+            "   a  (S)  d   f   g   h   j   k   l\n",
+            "     z   x   c   v   b   n   m"
+        )
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_json_renderer() {
+    assert_eq!(
+        JsonRenderer.render(&GuessFeedback::new("rusty", "rusts")),
+        concat!(
+            r#"{"guess":"rusts","solved":false,"letters":["#,
+            r#"{"letter":"r","status":"correct"},{"letter":"u","status":"correct"},{"letter":"s","status":"correct"},"#,
+            r#"{"letter":"t","status":"correct"},{"letter":"s","status":"absent"}]}"#
+        )
+    );
+    assert_eq!(escape_json("a\"b\\c\n"), "a\\\"b\\\\c\\u000a");
+
+    assert_eq!(
+        JsonRenderer.render_keyboard(&keyboard(AppLanguage::EN, &[GuessFeedback::new("rusty", "fishy")])),
+        r#"{"keyboard":{"y":"correct","i":"absent","s":"correct","f":"absent","h":"absent"}}"#
+    );
+}
@@ -0,0 +1,116 @@
+/// The status of a single letter of a guess
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum LetterStatus {
+    /// The letter is at the correct position.
+    Correct,
+    /// The word contains the letter, but at a different position.
+    Present,
+    /// The word does not contain the letter, or all of its occurrences are already accounted for.
+    Absent
+}
+
+/// Score a guess against the solution the way wordle does.
+///
+/// Letters at the correct position are marked [LetterStatus::Correct] first and consume
+/// one occurrence of that letter in the solution each. The remaining letters are then marked
+/// [LetterStatus::Present] from left to right for as long as unconsumed occurrences are left,
+/// everything else is [LetterStatus::Absent].
+///
+/// # Arguments
+/// * `solution_word` - A string slice that holds the word to guess
+/// * `guessed_word` - A string slice that holds the guess, expected to be of the same length
+pub fn score(solution_word: &str, guessed_word: &str) -> Vec<LetterStatus> {
+    let solution_characters: Vec<char> = solution_word.chars().collect();
+    let guessed_characters: Vec<char> = guessed_word.chars().collect();
+
+    let mut result: Vec<LetterStatus> = vec![LetterStatus::Absent; guessed_characters.len()];
+    // words are short, a list is faster than a map here, which matters to the solver
+    let mut remaining: Vec<(char, usize)> = Vec::with_capacity(solution_characters.len());
+
+    for (i, solution_character) in solution_characters.iter().enumerate() {
+        if guessed_characters.get(i) == Some(solution_character) {
+            result[i] = LetterStatus::Correct;
+        } else {
+            match remaining.iter_mut().find(|(character, _)| character == solution_character) {
+                Some((_, count)) => *count += 1,
+                None => remaining.push((*solution_character, 1))
+            }
+        }
+    }
+
+    for (i, guessed_character) in guessed_characters.iter().enumerate() {
+        if result[i] == LetterStatus::Correct {
+            continue;
+        }
+
+        if let Some((_, count)) = remaining.iter_mut().find(|(character, _)| character == guessed_character) {
+            if *count > 0 {
+                *count -= 1;
+                result[i] = LetterStatus::Present;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+use LetterStatus::{Absent, Correct, Present};
+
+#[cfg(test)]
+#[test]
+fn test_score_distinct_letters() {
+    assert_eq!(score("rusty", "rusty"), vec![Correct, Correct, Correct, Correct, Correct]);
+    assert_eq!(score("rusty", "fishy"), vec![Absent, Absent, Correct, Absent, Correct]);
+    assert_eq!(score("rusty", "tours"), vec![Present, Absent, Present, Present, Present]);
+    assert_eq!(score("rusty", "lusty"), vec![Absent, Correct, Correct, Correct, Correct]);
+    assert_eq!(score("rusty", "plank"), vec![Absent, Absent, Absent, Absent, Absent]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_score_double_letters_in_guess() {
+    // only one 'e' in the solution, the green one consumes it
+    assert_eq!(score("those", "geese"), vec![Absent, Absent, Absent, Correct, Correct]);
+    // one 'e' in the solution, only the first misplaced 'e' is yellow
+    assert_eq!(score("crane", "eerie"), vec![Absent, Absent, Present, Absent, Correct]);
+    assert_eq!(score("abide", "speed"), vec![Absent, Absent, Present, Absent, Present]);
+    // one 'l' in the solution, the green one wins even though the yellow one comes first
+    assert_eq!(score("world", "hello"), vec![Absent, Absent, Absent, Correct, Present]);
+    assert_eq!(score("hotel", "llama"), vec![Present, Absent, Absent, Absent, Absent]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_score_double_letters_in_solution() {
+    // two 'e' in the solution, a single guessed 'e' is marked once
+    assert_eq!(score("geese", "those"), vec![Absent, Absent, Absent, Correct, Correct]);
+    assert_eq!(score("eerie", "crane"), vec![Absent, Present, Absent, Absent, Correct]);
+    // both occurrences are found
+    assert_eq!(score("geese", "eerie"), vec![Present, Correct, Absent, Absent, Correct]);
+    assert_eq!(score("sassy", "asset"), vec![Present, Present, Correct, Absent, Absent]);
+    assert_eq!(score("sassy", "sassy"), vec![Correct, Correct, Correct, Correct, Correct]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_score_triple_letters() {
+    // three 'e' in the guess, one in the solution
+    assert_eq!(score("crane", "eeeee"), vec![Absent, Absent, Absent, Absent, Correct]);
+    assert_eq!(score("elder", "eerie"), vec![Correct, Present, Present, Absent, Absent]);
+    // three 's' in the solution
+    assert_eq!(score("sassy", "sissy"), vec![Correct, Absent, Correct, Correct, Correct]);
+    assert_eq!(score("sassy", "mosss"), vec![Absent, Absent, Correct, Correct, Present]);
+    assert_eq!(score("sassy", "basis"), vec![Absent, Correct, Correct, Absent, Present]);
+    // three 'e' in both words
+    assert_eq!(score("eerie", "emcee"), vec![Correct, Absent, Absent, Present, Correct]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_score_length_mismatch() {
+    assert_eq!(score("rusty", "rust"), vec![Correct, Correct, Correct, Correct]);
+    assert_eq!(score("rust", "rusty"), vec![Correct, Correct, Correct, Correct, Absent]);
+}
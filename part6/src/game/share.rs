@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+use console::Emoji;
+
+use crate::game::engine::Game;
+use crate::game::scoring::LetterStatus;
+
+static CORRECT: Emoji<'_, '_> = Emoji("🟩", "G");
+static PRESENT: Emoji<'_, '_> = Emoji("🟨", "Y");
+static ABSENT: Emoji<'_, '_> = Emoji("⬛", "-");
+
+/// Format a spoiler-free summary of a game that can be pasted into a chat, e.g.
+///
+/// ```text
+/// wordle-rs 2022-07-01 3/6
+/// ⬛🟨⬛⬛⬛
+/// ⬛🟩🟨⬛🟨
+/// 🟩🟩🟩🟩🟩
+/// ```
+///
+/// Lost games are shown as `X/6`, games played in hard mode are marked with `*`.
+///
+/// # Arguments
+/// * `game` - The [Game] to summarize
+/// * `played_at` - The day the game was played at
+/// * `emoji` - Use colored squares if true, ASCII characters otherwise
+pub fn format_share(game: &Game, played_at: NaiveDate, emoji: bool) -> String {
+    let attempts = match game.is_won() {
+        true => game.guesses().len().to_string(),
+        false => String::from("X")
+    };
+
+    let mut lines: Vec<String> = vec![format!(
+        "wordle-rs {} {}/{}{}",
+        played_at, attempts, game.max_attempts(), if game.is_hard_mode() { "*" } else { "" }
+    )];
+
+    for feedback in game.guesses() {
+        lines.push(feedback.letters.iter().map(|letter| {
+            let tile = match letter.status {
+                LetterStatus::Correct => &CORRECT,
+                LetterStatus::Present => &PRESENT,
+                LetterStatus::Absent => &ABSENT
+            };
+
+            if emoji { tile.0 } else { tile.1 }
+        }).collect());
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+use crate::dictionary::{DictionaryEntry, WordRole};
+
+#[cfg(test)]
+#[test]
+fn test_format_share() {
+    let played_at = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 6)
+        .with_guesses(&[String::from("tours"), String::from("rusty")]);
+
+    assert_eq!(format_share(&game, played_at, true), "wordle-rs 2022-07-01 2/6\n🟨⬛🟨🟨🟨\n🟩🟩🟩🟩🟩");
+    assert_eq!(format_share(&game, played_at, false), "wordle-rs 2022-07-01 2/6\nY-YYY\nGGGGG");
+
+    let game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 1)
+        .with_hard_mode(true)
+        .with_guesses(&[String::from("fishy")]);
+
+    assert_eq!(format_share(&game, played_at, false), "wordle-rs 2022-07-01 X/1*\n--G-G");
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use crate::dictionary::{DictionaryEntry, WordRole};
+use crate::game::feedback::{GuessFeedback, LetterFeedback};
+use crate::game::hard_mode::check_hard_mode;
+use crate::game::scoring::{LetterStatus, score};
+
+/// The number of candidates the information of a guess is estimated from, see [best_guess]
+const SAMPLE_SIZE: usize = 500;
+
+/// The words that can still be the solution and the guess that narrows them down the most
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hint {
+    /// The solutions matching the feedback of every guess, in the order of the words given
+    pub candidates: Vec<String>,
+    /// The guess with the highest expected information, see [best_guess]
+    pub suggestion: Option<String>
+}
+
+/// Parse the feedback of a guess written as a pattern like in the shared result:
+/// `G` for a correct letter, `Y` for a present letter and `-` for an absent letter,
+/// e.g. `crane` and `-Y--G`. Lowercase letters, `.` and the colored squares are accepted, too.
+///
+/// # Arguments
+/// * `guessed_word` - A string slice that holds the guess
+/// * `pattern` - A string slice that holds one status per letter of the guess
+pub fn parse_feedback(guessed_word: &str, pattern: &str) -> Result<GuessFeedback, String> {
+    let statuses: Vec<LetterStatus> = pattern.chars()
+        .map(|character| match character {
+            'G' | 'g' | '🟩' => Ok(LetterStatus::Correct),
+            'Y' | 'y' | '🟨' => Ok(LetterStatus::Present),
+            '-' | '.' | '⬛' | '⬜' => Ok(LetterStatus::Absent),
+            _ => Err(format!("'{}' is not a valid status, use G (correct), Y (present) or - (absent)", character))
+        })
+        .collect::<Result<Vec<LetterStatus>, String>>()?;
+
+    if statuses.len() != guessed_word.chars().count() {
+        return Err(format!("The pattern '{}' must have one status for each letter of '{}'", pattern, guessed_word));
+    }
+
+    Ok(GuessFeedback {
+        letters: guessed_word.chars()
+            .zip(statuses)
+            .map(|(letter, status)| LetterFeedback { letter, status })
+            .collect()
+    })
+}
+
+/// Returns true if scoring every previous guess against word gives the same feedback
+pub fn is_candidate(word: &str, guesses: &[GuessFeedback]) -> bool {
+    guesses.iter().all(|feedback| {
+        let statuses = score(word, &feedback.word());
+
+        statuses.len() == feedback.letters.len()
+            && statuses.iter().zip(&feedback.letters).all(|(status, letter)| *status == letter.status)
+    })
+}
+
+/// Return the guess that splits the candidates into the most even groups of equal feedback,
+/// i.e. the guess with the highest expected information. Ties are resolved in favor of
+/// guesses that can be the solution themselves, then in the order of the guesses given.
+/// With more than [SAMPLE_SIZE] candidates, the information is estimated from an evenly spread
+/// sample of them to keep the hint fast for large dictionaries.
+///
+/// # Arguments
+/// * `guesses` - The words that can be guessed
+/// * `candidates` - The words that can still be the solution
+pub fn best_guess<'a>(guesses: &[&'a str], candidates: &[&'a str]) -> Option<&'a str> {
+    match candidates.len() {
+        0 => return None,
+        1 | 2 => return Some(candidates[0]),
+        _ => ()
+    }
+
+    let sample: Vec<&str> = candidates.iter()
+        .step_by(candidates.len().div_ceil(SAMPLE_SIZE))
+        .copied()
+        .collect();
+
+    let mut best: Option<(&str, f64, bool)> = None;
+
+    for guess in guesses {
+        let information = expected_information(guess, &sample);
+        let is_candidate = candidates.contains(guess);
+
+        let is_better = match best {
+            None => true,
+            Some((_, best_information, best_is_candidate)) => information > best_information + f64::EPSILON
+                || ((information - best_information).abs() <= f64::EPSILON && is_candidate && !best_is_candidate)
+        };
+
+        if is_better {
+            best = Some((guess, information, is_candidate));
+        }
+    }
+
+    best.map(|(guess, _, _)| guess)
+}
+
+/// The entropy in bits of the feedback the guess gives over all candidates
+fn expected_information(guess: &str, candidates: &[&str]) -> f64 {
+    let mut groups: HashMap<u64, usize> = HashMap::new();
+
+    for candidate in candidates {
+        // the feedback as a number in base 3, cheaper to hash than the statuses
+        let key = score(candidate, guess).iter().fold(0, |key, status| key * 3 + match status {
+            LetterStatus::Correct => 2,
+            LetterStatus::Present => 1,
+            LetterStatus::Absent => 0
+        });
+
+        *groups.entry(key).or_insert(0) += 1;
+    }
+
+    let total = candidates.len() as f64;
+
+    groups.values()
+        .map(|count| {
+            let probability = *count as f64 / total;
+            -probability * probability.log2()
+        })
+        .sum()
+}
+
+/// Find the solutions matching the feedback so far and suggest the next guess.
+/// Every word can be suggested, while only words with [WordRole::Solution] are candidates.
+///
+/// # Arguments
+/// * `words` - The words of the dictionary with the length of the solution
+/// * `guesses` - The feedback of all previous guesses
+/// * `hard_mode` - Only suggest guesses that are valid in hard mode, see [check_hard_mode]
+pub fn hint(words: &[DictionaryEntry], guesses: &[GuessFeedback], hard_mode: bool) -> Hint {
+    let candidates: Vec<&str> = words.iter()
+        .filter(|entry| entry.role == WordRole::Solution && is_candidate(&entry.word, guesses))
+        .map(|entry| entry.word.as_str())
+        .collect();
+
+    let allowed_guesses: Vec<&str> = words.iter()
+        .map(|entry| entry.word.as_str())
+        .filter(|word| !hard_mode || check_hard_mode(guesses, word).is_ok())
+        .collect();
+
+    Hint {
+        suggestion: best_guess(&allowed_guesses, &candidates).map(String::from),
+        candidates: candidates.into_iter().map(String::from).collect()
+    }
+}
+
+/// Format a hint for the terminal, listing at most limit candidates
+pub fn format_hint(hint: &Hint, limit: usize) -> String {
+    if hint.candidates.is_empty() {
+        return String::from("No word of the dictionary matches the feedback.");
+    }
+
+    let mut candidates = hint.candidates.iter()
+        .take(limit)
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    if hint.candidates.len() > limit {
+        candidates.push_str(&format!(" and {} more", hint.candidates.len() - limit));
+    }
+
+    let mut lines = vec![match hint.candidates.len() {
+        1 => format!("1 possible word: {}", candidates),
+        count => format!("{} possible words: {}", count, candidates)
+    }];
+
+    if let Some(suggestion) = &hint.suggestion {
+        lines.push(format!("Suggested guess: {}", suggestion));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+fn entry(word: &str, role: WordRole) -> DictionaryEntry {
+    DictionaryEntry { word: String::from(word), guessed: false, lost: false, role }
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_feedback() {
+    assert_eq!(parse_feedback("tours", "Y-YYY").unwrap(), GuessFeedback::new("rusty", "tours"));
+    assert_eq!(parse_feedback("fishy", "--g-g").unwrap(), GuessFeedback::new("rusty", "fishy"));
+    assert_eq!(parse_feedback("geese", "⬛⬛⬛🟩🟩").unwrap(), GuessFeedback::new("those", "geese"));
+
+    assert!(parse_feedback("tours", "Y-YY").is_err());
+    assert!(parse_feedback("tours", "Y-YYX").is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_is_candidate() {
+    let guesses = vec![GuessFeedback::new("rusty", "tours")];
+
+    assert!(is_candidate("rusty", &guesses));
+    assert!(!is_candidate("lusty", &guesses));
+    assert!(!is_candidate("tours", &guesses));
+    assert!(is_candidate("anything", &[]));
+
+    // a second 'e' would have been marked
+    let guesses = vec![GuessFeedback::new("those", "geese")];
+    assert!(is_candidate("those", &guesses));
+    assert!(!is_candidate("theme", &guesses));
+}
+
+#[cfg(test)]
+#[test]
+fn test_best_guess() {
+    assert_eq!(best_guess(&["rusty"], &[]), None);
+    assert_eq!(best_guess(&["abcde"], &["rusty"]), Some("rusty"));
+
+    // "brick" and "frown" tell all three candidates apart, the candidates themselves can't
+    let candidates = ["bunny", "funny", "runny"];
+    assert_eq!(best_guess(&["bunny", "funny", "runny", "brick", "frown"], &candidates), Some("brick"));
+    assert_eq!(best_guess(&["bunny", "fibre"], &candidates), Some("fibre"));
+
+    // equal information, the candidate wins
+    assert_eq!(best_guess(&["bxxxx", "bunny"], &["bunny", "cunny", "dunny"]), Some("bunny"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_hint() {
+    let words = vec![
+        entry("fishy", WordRole::Solution),
+        entry("lusty", WordRole::Solution),
+        entry("musty", WordRole::Solution),
+        entry("rusty", WordRole::Solution),
+        entry("tours", WordRole::Solution),
+        entry("dusty", WordRole::Guess),
+        entry("lmrxx", WordRole::Guess)
+    ];
+
+    let guesses = vec![GuessFeedback::new("rusty", "fishy")];
+
+    // guess-only words are suggested, but never candidates
+    let easy = hint(&words, &guesses, false);
+    assert_eq!(easy.candidates, vec!["lusty", "musty", "rusty"]);
+    assert_eq!(easy.suggestion, Some(String::from("lmrxx")));
+
+    let hard = hint(&words, &guesses, true);
+    assert_eq!(hard.candidates, easy.candidates);
+    assert_eq!(hard.suggestion, Some(String::from("lusty")));
+
+    assert!(hint(&words, &[GuessFeedback::new("plank", "fishy")], false).candidates.is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_hint() {
+    let hint = Hint {
+        candidates: vec![String::from("lusty"), String::from("musty"), String::from("rusty")],
+        suggestion: Some(String::from("lmrxx"))
+    };
+
+    assert_eq!(format_hint(&hint, 5), "3 possible words: lusty, musty, rusty\nSuggested guess: lmrxx");
+    assert_eq!(format_hint(&hint, 2), "3 possible words: lusty, musty and 1 more\nSuggested guess: lmrxx");
+    assert_eq!(format_hint(&Hint { candidates: vec![], suggestion: None }, 5), "No word of the dictionary matches the feedback.");
+}
@@ -5,12 +5,16 @@ use sys_locale::get_locale;
 #[derive(strum_macros::Display)]
 pub enum AppLanguage {
     DE,
-    EN
+    EN,
+    ES,
+    FR,
+    IT,
+    NL
 }
 
 /// Eliminate non-ASCII characters.
-/// Replace common german special characters with their matching counterparts.
-/// The parameter is expected to be lower case.
+/// Replace common german special characters with their matching counterparts. Accents, the diaeresis
+/// outside of german and the spanish 'ñ' are reduced to their base letter. The parameter is expected to be lower case.
 pub fn replace_unicode(word: &str, app_language: AppLanguage) -> String {
     match app_language {
         AppLanguage::DE => {
@@ -39,11 +43,17 @@ pub fn get_app_language() -> AppLanguage {
     }
 }
 
+/// Parse the language of a locale like `de`, `fr-CH` or `nl_BE`, ignoring the region.
+/// Languages without a dictionary fall back to [AppLanguage::EN].
 pub fn parse_app_language(locale_str: &str) -> AppLanguage {
-    match locale_str {
+    let language = locale_str.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+
+    match language.as_str() {
         "de" => AppLanguage::DE,
-        "de-DE" => AppLanguage::DE,
-        "de-AT" => AppLanguage::DE,
+        "es" => AppLanguage::ES,
+        "fr" => AppLanguage::FR,
+        "it" => AppLanguage::IT,
+        "nl" => AppLanguage::NL,
         _ => AppLanguage::EN
     }
 }
@@ -60,6 +70,24 @@ fn test_replace_unicode() {
     assert_eq!(replace_unicode("geschoß", AppLanguage::EN), "geschoss");
     assert_eq!(replace_unicode("zäh", AppLanguage::EN), "zah");
     assert_eq!(replace_unicode("lüge", AppLanguage::EN), "luge");
+
+    assert_eq!(replace_unicode("cœur", AppLanguage::FR), "coeur");
+    assert_eq!(replace_unicode("ex æquo", AppLanguage::FR), "ex aequo");
+    assert_eq!(replace_unicode("élève", AppLanguage::FR), "eleve");
+    assert_eq!(replace_unicode("garçon", AppLanguage::FR), "garcon");
+    assert_eq!(replace_unicode("naïve", AppLanguage::FR), "naive");
+
+    assert_eq!(replace_unicode("niño", AppLanguage::ES), "nino");
+    assert_eq!(replace_unicode("árbol", AppLanguage::ES), "arbol");
+    assert_eq!(replace_unicode("pingüino", AppLanguage::ES), "pinguino");
+
+    assert_eq!(replace_unicode("ĳsje", AppLanguage::NL), "ijsje");
+    assert_eq!(replace_unicode("ideeën", AppLanguage::NL), "ideeen");
+    assert_eq!(replace_unicode("één", AppLanguage::NL), "een");
+
+    assert_eq!(replace_unicode("città", AppLanguage::IT), "citta");
+    assert_eq!(replace_unicode("perché", AppLanguage::IT), "perche");
+    assert_eq!(replace_unicode("più", AppLanguage::IT), "piu");
 }
 
 #[cfg(test)]
@@ -80,8 +108,14 @@ fn test_parse_app_language() {
     assert_eq!(parse_app_language("de"), AppLanguage::DE);
     assert_eq!(parse_app_language("de-AT"), AppLanguage::DE);
     assert_eq!(parse_app_language("de-DE"), AppLanguage::DE);
+    assert_eq!(parse_app_language("de-CH"), AppLanguage::DE);
     assert_eq!(parse_app_language("en"), AppLanguage::EN);
-    assert_eq!(parse_app_language("it"), AppLanguage::EN);
+    assert_eq!(parse_app_language("es-MX"), AppLanguage::ES);
+    assert_eq!(parse_app_language("fr"), AppLanguage::FR);
+    assert_eq!(parse_app_language("fr_CA"), AppLanguage::FR);
+    assert_eq!(parse_app_language("it"), AppLanguage::IT);
+    assert_eq!(parse_app_language("NL-be"), AppLanguage::NL);
+    assert_eq!(parse_app_language("pt-BR"), AppLanguage::EN);
     assert_eq!(parse_app_language(""), AppLanguage::EN);
 
     assert_ne!(parse_app_language("de-DE"), AppLanguage::EN);
@@ -0,0 +1,313 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+use crate::game::render::escape_json;
+use crate::statistics::{GameResult, Statistics};
+
+/// The days of the results a [Leaderboard] is built from
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum Period {
+    /// The current week, starting on Monday
+    Week,
+    /// The current month
+    Month,
+    /// Every result ever recorded
+    All
+}
+
+impl Period {
+    /// The first day of the period today is part of, [None] for [Period::All]
+    pub fn start(&self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Period::Week => Some(today - Duration::days(today.weekday().num_days_from_monday() as i64)),
+            Period::Month => today.with_day(1),
+            Period::All => None
+        }
+    }
+}
+
+/// Parse a [Period]: `week`, `month` or `all`
+pub fn parse_period(period_str: &str) -> Result<Period, String> {
+    match period_str {
+        "week" => Ok(Period::Week),
+        "month" => Ok(Period::Month),
+        "all" | "all-time" => Ok(Period::All),
+        _ => Err(format!("'{}' is not a valid period, use week, month or all", period_str))
+    }
+}
+
+/// How a [Leaderboard] is printed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LeaderboardFormat {
+    Table,
+    Csv,
+    Json
+}
+
+/// Parse a [LeaderboardFormat]: `table`, `csv` or `json`
+pub fn parse_leaderboard_format(format_str: &str) -> Result<LeaderboardFormat, String> {
+    match format_str {
+        "table" => Ok(LeaderboardFormat::Table),
+        "csv" => Ok(LeaderboardFormat::Csv),
+        "json" => Ok(LeaderboardFormat::Json),
+        _ => Err(format!("'{}' is not a valid format, use table, csv or json", format_str))
+    }
+}
+
+/// The statistics of a single player within a period
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub player: String,
+    pub played: usize,
+    pub won: usize,
+    pub win_percentage: usize,
+    /// The average number of guesses of the games won, [None] if no game was won
+    pub average_guesses: Option<f64>,
+    pub current_streak: usize
+}
+
+/// Players ranked by win rate, then by the fewest guesses on average, then by the longest current streak
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leaderboard {
+    pub period: Period,
+    pub entries: Vec<LeaderboardEntry>
+}
+
+impl Leaderboard {
+    /// Rank the players by their results within period. Players without results in the period are left out.
+    /// Players with the same win rate, average and streak share a rank.
+    ///
+    /// # Arguments
+    /// * `results` - The name of the player of every game result, ordered by the day they were played at
+    /// * `period` - The [Period] to rank the players in
+    /// * `today` - The current day
+    pub fn new(results: &[(String, GameResult)], period: Period, today: NaiveDate) -> Leaderboard {
+        let start = period.start(today);
+
+        let mut results_by_player: BTreeMap<&str, Vec<GameResult>> = BTreeMap::new();
+
+        for (player, result) in results {
+            if start.is_none_or(|start| result.played_at >= start) && result.played_at <= today {
+                results_by_player.entry(player).or_default().push(result.clone());
+            }
+        }
+
+        let mut entries: Vec<LeaderboardEntry> = results_by_player.iter()
+            .map(|(player, results)| {
+                let statistics = Statistics::new(results, today);
+                let guesses: usize = results.iter()
+                    .filter(|result| result.won)
+                    .map(|result| result.attempts)
+                    .sum();
+
+                LeaderboardEntry {
+                    rank: 0,
+                    player: String::from(*player),
+                    played: statistics.played,
+                    won: statistics.won,
+                    win_percentage: statistics.win_percentage(),
+                    average_guesses: match statistics.won {
+                        0 => None,
+                        won => Some(guesses as f64 / won as f64)
+                    },
+                    current_streak: statistics.current_streak
+                }
+            })
+            .collect();
+
+        entries.sort_by(|a, b| compare_entries(a, b).then_with(|| a.player.cmp(&b.player)));
+
+        for i in 0..entries.len() {
+            entries[i].rank = match i {
+                0 => 1,
+                _ if compare_entries(&entries[i - 1], &entries[i]) == Ordering::Equal => entries[i - 1].rank,
+                _ => i + 1
+            };
+        }
+
+        Leaderboard { period, entries }
+    }
+}
+
+/// Order the better of two entries first, ignoring the name of the player
+fn compare_entries(a: &LeaderboardEntry, b: &LeaderboardEntry) -> Ordering {
+    // compare the exact win rates instead of the rounded percentages
+    (b.won * a.played).cmp(&(a.won * b.played))
+        .then_with(|| match (a.average_guesses, b.average_guesses) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal
+        })
+        .then_with(|| b.current_streak.cmp(&a.current_streak))
+}
+
+/// Format the leaderboard in the format given, see [LeaderboardFormat]
+pub fn format_leaderboard(leaderboard: &Leaderboard, format: LeaderboardFormat) -> String {
+    match format {
+        LeaderboardFormat::Table => format_table(leaderboard),
+        LeaderboardFormat::Csv => format_csv(leaderboard),
+        LeaderboardFormat::Json => format_json(leaderboard)
+    }
+}
+
+fn format_average(average_guesses: Option<f64>) -> String {
+    match average_guesses {
+        Some(average) => format!("{:.2}", average),
+        None => String::from("-")
+    }
+}
+
+fn format_table(leaderboard: &Leaderboard) -> String {
+    if leaderboard.entries.is_empty() {
+        return String::from(match leaderboard.period {
+            Period::Week => "No games were played this week.",
+            Period::Month => "No games were played this month.",
+            Period::All => "No games were played yet."
+        });
+    }
+
+    let player_width = leaderboard.entries.iter()
+        .map(|entry| entry.player.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Player".len());
+
+    let mut lines = vec![format!(
+        "{:>4}  {:<width$}  {:>6}  {:>5}  {:>8}  {:>6}",
+        "Rank", "Player", "Played", "Win %", "Guesses", "Streak", width = player_width
+    )];
+
+    for entry in &leaderboard.entries {
+        lines.push(format!(
+            "{:>4}  {:<width$}  {:>6}  {:>5}  {:>8}  {:>6}",
+            entry.rank, entry.player, entry.played, entry.win_percentage,
+            format_average(entry.average_guesses), entry.current_streak, width = player_width
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn format_csv(leaderboard: &Leaderboard) -> String {
+    let mut lines = vec![String::from("rank,player,played,won,win_percentage,average_guesses,current_streak")];
+
+    for entry in &leaderboard.entries {
+        let player = match entry.player.contains(&[',', '"', '\n'][..]) {
+            true => format!("\"{}\"", entry.player.replace('"', "\"\"")),
+            false => entry.player.clone()
+        };
+
+        lines.push(format!(
+            "{},{},{},{},{},{},{}",
+            entry.rank, player, entry.played, entry.won, entry.win_percentage,
+            entry.average_guesses.map(|average| format!("{:.2}", average)).unwrap_or_default(),
+            entry.current_streak
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn format_json(leaderboard: &Leaderboard) -> String {
+    let entries: Vec<String> = leaderboard.entries.iter()
+        .map(|entry| format!(
+            "{{\"rank\":{},\"player\":\"{}\",\"played\":{},\"won\":{},\"win_percentage\":{},\"average_guesses\":{},\"current_streak\":{}}}",
+            entry.rank, escape_json(&entry.player), entry.played, entry.won, entry.win_percentage,
+            entry.average_guesses.map(|average| format!("{:.2}", average)).unwrap_or_else(|| String::from("null")),
+            entry.current_streak
+        ))
+        .collect();
+
+    format!("{{\"period\":\"{}\",\"players\":[{}]}}", leaderboard.period, entries.join(","))
+}
+
+#[cfg(test)]
+fn day(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2022, 7, day).unwrap()
+}
+
+#[cfg(test)]
+fn result(player: &str, played_at: u32, won: bool, attempts: usize) -> (String, GameResult) {
+    (String::from(player), GameResult { played_at: day(played_at), won, attempts })
+}
+
+#[cfg(test)]
+#[test]
+fn test_period() {
+    // 2022-07-06 is a Wednesday
+    assert_eq!(Period::Week.start(day(6)), Some(day(4)));
+    assert_eq!(Period::Week.start(day(4)), Some(day(4)));
+    assert_eq!(Period::Month.start(day(6)), Some(day(1)));
+    assert_eq!(Period::All.start(day(6)), None);
+
+    assert_eq!(parse_period("month"), Ok(Period::Month));
+    assert!(parse_period("year").is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_leaderboard() {
+    let results = vec![
+        result("carol", 1, true, 2),
+        result("alice", 4, true, 3),
+        result("bob", 4, true, 4),
+        result("carol", 4, false, 6),
+        result("alice", 5, true, 4),
+        result("bob", 5, true, 3),
+        result("dave", 5, false, 6),
+        result("carol", 6, true, 1)
+    ];
+
+    let leaderboard = Leaderboard::new(&results, Period::Week, day(6));
+    let ranking: Vec<(usize, &str)> = leaderboard.entries.iter()
+        .map(|entry| (entry.rank, entry.player.as_str()))
+        .collect();
+
+    // alice and bob share the first rank, carol's first game was last week
+    assert_eq!(ranking, vec![(1, "alice"), (1, "bob"), (3, "carol"), (4, "dave")]);
+
+    assert_eq!(leaderboard.entries[0].average_guesses, Some(3.5));
+    assert_eq!(leaderboard.entries[0].current_streak, 2);
+    assert_eq!(leaderboard.entries[2].played, 2);
+    assert_eq!(leaderboard.entries[2].win_percentage, 50);
+    assert_eq!(leaderboard.entries[3].average_guesses, None);
+
+    // carol's game of last week counts for the month
+    let leaderboard = Leaderboard::new(&results, Period::Month, day(6));
+    assert_eq!(leaderboard.entries[2].player, "carol");
+    assert_eq!(leaderboard.entries[2].win_percentage, 66);
+
+    assert!(Leaderboard::new(&results, Period::Week, day(12)).entries.is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_leaderboard() {
+    let leaderboard = Leaderboard::new(&[
+        result("alice", 5, true, 3),
+        result("Bob \"the builder\", jr", 5, false, 6)
+    ], Period::Week, day(6));
+
+    assert_eq!(format_leaderboard(&leaderboard, LeaderboardFormat::Table), "\
+Rank  Player                 Played  Win %   Guesses  Streak
+   1  alice                       1    100      3.00       1
+   2  Bob \"the builder\", jr       1      0         -       0");
+
+    assert_eq!(format_leaderboard(&leaderboard, LeaderboardFormat::Csv), "\
+rank,player,played,won,win_percentage,average_guesses,current_streak
+1,alice,1,1,100,3.00,1
+2,\"Bob \"\"the builder\"\", jr\",1,0,0,,0");
+
+    assert_eq!(format_leaderboard(&leaderboard, LeaderboardFormat::Json), "\
+{\"period\":\"week\",\"players\":[\
+{\"rank\":1,\"player\":\"alice\",\"played\":1,\"won\":1,\"win_percentage\":100,\"average_guesses\":3.00,\"current_streak\":1},\
+{\"rank\":2,\"player\":\"Bob \\\"the builder\\\", jr\",\"played\":1,\"won\":0,\"win_percentage\":0,\"average_guesses\":null,\"current_streak\":0}]}");
+
+    assert_eq!(format_leaderboard(&Leaderboard::new(&[], Period::Month, day(6)), LeaderboardFormat::Table), "No games were played this month.");
+}
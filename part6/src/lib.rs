@@ -1,8 +1,14 @@
 pub mod dictionary;
+pub mod statistics;
 pub mod text;
 pub mod lang;
 pub mod db;
+pub mod game;
+pub mod player;
+pub mod leaderboard;
 
 #[macro_use]
 extern crate diesel;
+#[macro_use]
+extern crate diesel_migrations;
 
@@ -1,137 +1,441 @@
+use std::{env, fs};
 use std::io::stdin;
+use std::process::exit;
+use chrono::NaiveDate;
 use clap::Parser;
-use console::style;
-use fancy_hangman::lang::locale::{get_app_language, parse_app_language, replace_unicode};
-use fancy_hangman::dictionary::{Dictionary, get_dictionary};
+use console::Term;
+use dotenv::dotenv;
+use fancy_hangman::lang::locale::{AppLanguage, get_app_language, parse_app_language, replace_unicode};
+use fancy_hangman::dictionary::{Dictionary, DictionaryEntry, get_player_dictionary, WordFilter, WordRole};
+use fancy_hangman::game::daily::get_day_boundary;
+use fancy_hangman::game::engine::{Game, GuessError};
+use fancy_hangman::game::feedback::GuessFeedback;
+use fancy_hangman::game::keyboard::keyboard;
+use fancy_hangman::game::render::{FeedbackRenderer, get_renderer};
+use fancy_hangman::game::share::format_share;
+use fancy_hangman::game::solver::{format_hint, hint};
+use fancy_hangman::player::get_player_name;
+use fancy_hangman::statistics::{format_statistics, GameResult, get_statistics_store, ResultKind, Statistics, StatisticsStore};
+
+/// The number of unused solutions at which the player is warned that words will repeat soon
+const UNUSED_WORDS_WARNING: usize = 30;
 
 /// Play wordle, a word guessing game!
 #[derive(Parser)]
 struct Arguments {
     language: Option<String>,
-    dictionary: Option<String>
+    dictionary: Option<String>,
+    /// How guesses are displayed: "ansi", "plain" or "json"
+    #[clap(long)]
+    output: Option<String>,
+    /// Any revealed hints must be used in subsequent guesses
+    #[clap(long)]
+    hard: bool,
+    /// The number of characters of the word to guess
+    #[clap(long, default_value_t = 5)]
+    length: usize,
+    /// The number of guesses
+    #[clap(long, default_value_t = 6)]
+    attempts: usize,
+    /// Write the shareable result to this file instead of printing it
+    #[clap(long)]
+    share_file: Option<String>,
+    /// Players using the same seed and dictionary get the same word each day. Defaults to WORDLE_SEED
+    #[clap(long)]
+    seed: Option<String>,
+    /// When the next word starts: "local", "utc", an offset like "+02:00" or a timezone like "Europe/Vienna". Defaults to WORDLE_TIMEZONE or utc
+    #[clap(long)]
+    timezone: Option<String>,
+    /// Play random words back to back without using up the daily word
+    #[clap(long)]
+    practice: bool,
+    /// List the puzzles of previous days and choose one to play
+    #[clap(long)]
+    archive: bool,
+    /// Play the puzzle of a previous day from the archive, e.g. 2022-07-01
+    #[clap(long)]
+    day: Option<String>,
+    /// Your name when sharing a database dictionary with others. Defaults to WORDLE_PLAYER or the name of the OS user
+    #[clap(long)]
+    player: Option<String>
 }
 fn main() {
-    print_welcome();
-
     let args = Arguments::parse();
 
-    let app_language = match args.language {
+    print_welcome(args.length, args.attempts, args.practice);
+
+    let app_language = match &args.language {
         None => get_app_language(),
         Some(flag) => parse_app_language(flag.as_str())
     };
 
-    let dictionary: Box<dyn Dictionary> = match args.dictionary {
-        None => get_dictionary(app_language, String::from("text")),
-        Some(flag) => get_dictionary(app_language, flag)
+    let dictionary_flag = args.dictionary.clone().unwrap_or_else(|| String::from("text"));
+
+    let player = get_player_name(args.player.clone());
+
+    let dictionary: Box<dyn Dictionary> = match get_player_dictionary(app_language, dictionary_flag.clone(), &player) {
+        Ok(dictionary) => dictionary,
+        Err(error) => {
+            println!("Error opening the dictionary:\n{}", error);
+            exit(1);
+        }
+    };
+    let statistics_store: Box<dyn StatisticsStore> = match get_statistics_store(app_language, dictionary_flag.clone(), ResultKind::Daily, &player) {
+        Ok(statistics_store) => statistics_store,
+        Err(error) => {
+            println!("Error opening the statistics:\n{}", error);
+            exit(1);
+        }
+    };
+
+    let renderer: Box<dyn FeedbackRenderer> = match args.output.clone() {
+        None => get_renderer(String::from("ansi")),
+        Some(flag) => get_renderer(flag)
+    };
+
+    if args.practice {
+        practice(dictionary.as_ref(), renderer.as_ref(), app_language, args.length, args.attempts, args.hard);
+        return;
+    }
+
+    let today = match get_day_boundary(args.timezone.clone()) {
+        Ok(day_boundary) => day_boundary.today(),
+        Err(error) => {
+            println!("{}", error);
+            exit(1);
+        }
     };
 
-    let solution_option = dictionary.get_random_word();
+    if args.archive || args.day.is_some() {
+        archive(dictionary.as_ref(), renderer.as_ref(), app_language, dictionary_flag, &player, today, &args);
+        return;
+    }
+
+    dotenv().ok();
+    let seed = args.seed.unwrap_or_else(|| env::var("WORDLE_SEED").unwrap_or_default());
+
+    let solution_option = dictionary.get_daily_word(today, args.length, &seed);
 
     match solution_option {
-        None => println!("Maybe the dictionary is empty?"),
-        Some(solution) => {
+        Err(error) => println!("Error reading a word from the dictionary:\n{}", error),
+        Ok(None) => println!("Maybe the dictionary has no words with {} characters?", args.length),
+        Ok(Some(solution)) => {
+            warn_unused_words(dictionary.as_ref(), args.length);
+
+            let guesses = dictionary.get_guesses(&solution).unwrap_or_else(|error| {
+                println!("Error restoring your guesses:\n{}", error);
+                Vec::new()
+            });
+            let mut game = Game::new(solution, args.attempts)
+                .with_hard_mode(args.hard)
+                .with_guesses(&guesses);
+
+            if game.is_won() || game.is_lost() {
+                if game.guesses().is_empty() && game.is_won() {
+                    println!("{}", renderer.render(&GuessFeedback::new(&game.solution().word, &game.solution().word)));
+                }
 
-            if solution.guessed {
-                check_word(&solution.word, &solution.word);
+                for feedback in game.guesses() {
+                    println!("{}", renderer.render(feedback));
+                }
+
+                match game.is_won() {
+                    true => println!("You won! Come back tomorrow!"),
+                    false => println!("Better luck next time! Come back tomorrow!")
+                }
 
-                println!("You won! Come back tomorrow!");
+                if !game.guesses().is_empty() {
+                    share(&game, today, &args.share_file);
+                }
             } else {
-                let max_attempts = 6;
-
-                let mut full_match: bool = false;
-
-                let mut counter = 0;
-                while counter < max_attempts {
-                    let attempt: String = read_input(5);
-
-                    match dictionary.find_word(&attempt) {
-                        Some(_) => {
-                            let guesses: i32 = max_attempts - counter - 1;
-                            full_match = check_word(&solution.word, &attempt);
-
-                            if full_match == true {
-                                break;
-                            } else {
-                                if guesses > 1 {
-                                    println!("You now have {} guesses.", guesses);
-                                } else {
-                                    println!("This is your last guess.");
-                                }
-                            }
-
-                            if guesses == 0 { println!("Better luck next time!") }
-
-                            counter += 1;
-                        },
-                        None => println!("The guessed word is not in the word list.")
+                if !game.guesses().is_empty() {
+                    println!("Welcome back! Here are your guesses so far:");
+
+                    for feedback in game.guesses() {
+                        println!("{}", renderer.render(feedback));
                     }
+
+                    println!("{}", renderer.render_keyboard(&keyboard(app_language, game.guesses())));
+                    println!("You have {} guesses left.", game.remaining_attempts());
                 }
 
-                if full_match {
+                play(&mut game, dictionary.as_ref(), renderer.as_ref(), app_language, true);
+
+                let update_result = if game.is_won() {
                     println!("Congratulations! You won!");
-                    dictionary.guessed_word(solution);
+                    dictionary.guessed_word(game.solution().clone())
+                } else if game.is_lost() {
+                    dictionary.lost_word(game.solution().clone())
+                } else {
+                    Ok(())
+                };
+
+                if let Err(error) = update_result {
+                    println!("Error updating the dictionary:\n{}", error);
+                }
+
+                if game.is_won() || game.is_lost() {
+                    println!();
+                    share(&game, today, &args.share_file);
+
+                    let results = statistics_store.record_result(GameResult {
+                        played_at: today,
+                        won: game.is_won(),
+                        attempts: game.guesses().len()
+                    }).and_then(|_| statistics_store.get_results());
+
+                    println!();
+                    match results {
+                        Ok(results) => println!("{}", format_statistics(&Statistics::new(&results, today), 30)),
+                        Err(error) => println!("Error updating the statistics:\n{}", error)
+                    }
                 }
             }
         }
     }
 }
 
-fn read_input(word_len: usize) -> String {
-    let mut input: String = String::new();
+/// Read guesses until the game is over or stdin has been closed
+///
+/// # Arguments
+/// * `game` - The [Game] to play
+/// * `dictionary` - The [Dictionary] guesses are looked up in
+/// * `renderer` - The [FeedbackRenderer] used to display guesses and the keyboard
+/// * `app_language` - The language of the keyboard layout. See [AppLanguage]
+/// * `save_guesses` - Whether accepted guesses are stored in the dictionary, so the game can be resumed
+fn play(game: &mut Game, dictionary: &dyn Dictionary, renderer: &dyn FeedbackRenderer, app_language: AppLanguage, save_guesses: bool) {
+    while !game.is_won() && !game.is_lost() {
+        let attempt: String = match read_input(app_language) {
+            Some(input) => input,
+            None => break
+        };
+
+        if attempt == "?" {
+            match dictionary.get_entries(&WordFilter::new().with_word_len(game.word_len())) {
+                Ok(words) => println!("{}", format_hint(&hint(&words, game.guesses(), game.is_hard_mode()), 10)),
+                Err(error) => println!("Error reading the dictionary:\n{}", error)
+            }
+            continue;
+        }
 
-    loop {
-        stdin().read_line(&mut input).unwrap();
-        let polished = replace_unicode(input.to_lowercase().trim(), get_app_language());
+        match game.submit_guess(&attempt, dictionary) {
+            Ok(feedback) => {
+                if save_guesses {
+                    if let Err(error) = dictionary.save_guess(game.solution(), &attempt) {
+                        println!("Error saving your guess:\n{}", error);
+                    }
+                }
 
-        if !validate_user_input(&polished, word_len) {
-            println!("Invalid input: Your guess must have a size of {} characters. You entered {} characters.", word_len, polished.len());
+                println!("{}", renderer.render(&feedback));
 
-            input.clear();
-        } else {
-            input = polished.to_lowercase();
+                if !game.is_won() {
+                    println!("{}", renderer.render_keyboard(&keyboard(app_language, game.guesses())));
+                }
 
-            break;
+                if !game.is_won() {
+                    match game.remaining_attempts() {
+                        0 => println!("Better luck next time!"),
+                        1 => println!("This is your last guess."),
+                        guesses => println!("You now have {} guesses.", guesses)
+                    }
+                }
+            },
+            Err(GuessError::InvalidLength { expected, actual }) =>
+                println!("Invalid input: Your guess must have a size of {} characters. You entered {} characters.", expected, actual),
+            Err(GuessError::NotInWordList) => println!("The guessed word is not in the word list."),
+            Err(GuessError::HardMode(violation)) => println!("Hard mode: {}", violation),
+            Err(GuessError::GameOver) => break,
+            Err(GuessError::Dictionary(error)) => println!("Error looking up your guess:\n{}", error)
         }
     }
-
-    input
 }
 
-fn validate_user_input(user_input: &str, expected_len: usize) -> bool {
-    user_input.len() == expected_len
-}
+/// Play random words back to back until the player stops. Practice games neither use the daily word
+/// nor change the dictionary or the statistics.
+fn practice(dictionary: &dyn Dictionary, renderer: &dyn FeedbackRenderer, app_language: AppLanguage, word_len: usize, max_attempts: usize, hard_mode: bool) {
+    loop {
+        let solution = match dictionary.get_random_word(word_len) {
+            Err(error) => {
+                println!("Error reading a word from the dictionary:\n{}", error);
+                return;
+            },
+            Ok(None) => {
+                println!("Maybe the dictionary has no words with {} characters?", word_len);
+                return;
+            },
+            Ok(Some(solution)) => solution
+        };
+
+        // the word may have been the daily word, its state does not matter here
+        let mut game = Game::new(DictionaryEntry { guessed: false, lost: false, ..solution }, max_attempts)
+            .with_hard_mode(hard_mode);
+
+        play(&mut game, dictionary, renderer, app_language, false);
+
+        if game.is_won() {
+            println!("Congratulations! You won!");
+        } else if game.is_lost() {
+            println!("The word was '{}'.", game.solution().word);
+        } else {
+            return;
+        }
 
-fn check_word(solution_word: &str, guessed_word: &str) -> bool {
-    let guessed_characters: Vec<char> = guessed_word.chars().collect();
-    let solution_characters: Vec<char> = solution_word.chars().collect();
+        println!("Play again? [Y/n]");
 
-    for i in 0..guessed_word.len() {
-        let index: Option<usize> = solution_word.find(guessed_characters[i]);
+        match read_input(app_language) {
+            Some(answer) if answer.is_empty() || answer == "y" || answer == "yes" => println!(),
+            _ => return
+        }
+    }
+}
 
-        match index {
-            Some(_index) => {
-                if solution_characters[i] == guessed_characters[i] {
-                    print!("{} ", style(guessed_characters[i].to_string()).green())
-                } else {
-                    print!("{} ", style(guessed_characters[i].to_string()).yellow())
+/// Play the puzzle of a previous day. The day is taken from the arguments or chosen from a list of
+/// previous puzzles. The result is recorded as a [ResultKind::Archive] result, so it does not
+/// affect the statistics of the daily game.
+fn archive(dictionary: &dyn Dictionary, renderer: &dyn FeedbackRenderer, app_language: AppLanguage, dictionary_flag: String, player: &str, today: NaiveDate, args: &Arguments) {
+    let day_input = match &args.day {
+        Some(day) => day.clone(),
+        None => {
+            let days = match dictionary.get_archive_days(today, args.length) {
+                Ok(days) => days,
+                Err(error) => {
+                    println!("Error reading the archive:\n{}", error);
+                    return;
                 }
+            };
+
+            if days.is_empty() {
+                println!("There are no previous puzzles with {} characters. The archive is only kept by the db dictionary.", args.length);
+                return;
+            }
+
+            println!("Previous puzzles:");
+            for day in days {
+                println!("  {}", day);
             }
-            None => { print!("{} ", guessed_characters[i]) }
+            println!("Which day do you want to play?");
+
+            match read_input(app_language) {
+                Some(input) => input,
+                None => return
+            }
+        }
+    };
+
+    let day = match NaiveDate::parse_from_str(&day_input, "%Y-%m-%d") {
+        Ok(day) => day,
+        Err(_) => {
+            println!("'{}' is not a valid day, use e.g. 2022-07-01.", day_input);
+            return;
+        }
+    };
+
+    if day >= today {
+        println!("Only puzzles of previous days are part of the archive. Play today's word without --archive.");
+        return;
+    }
+
+    let solution = match dictionary.get_archive_word(day, args.length) {
+        Ok(Some(solution)) => solution,
+        Ok(None) => {
+            println!("There is no puzzle with {} characters for {} in the archive.", args.length, day);
+            return;
+        },
+        Err(error) => {
+            println!("Error reading the archive:\n{}", error);
+            return;
         }
+    };
+
+    println!("Puzzle of {}:", day);
+
+    // the state of the daily game is left untouched
+    let mut game = Game::new(DictionaryEntry { guessed: false, lost: false, ..solution }, args.attempts)
+        .with_hard_mode(args.hard);
+
+    play(&mut game, dictionary, renderer, app_language, false);
+
+    if game.is_won() {
+        println!("Congratulations! You won!");
+    } else if game.is_lost() {
+        println!("The word was '{}'.", game.solution().word);
+    } else {
+        return;
     }
 
     println!();
+    share(&game, day, &args.share_file);
+
+    let results = get_statistics_store(app_language, dictionary_flag, ResultKind::Archive, player)
+        .and_then(|statistics_store| {
+            statistics_store.record_result(GameResult {
+                played_at: day,
+                won: game.is_won(),
+                attempts: game.guesses().len()
+            })?;
+
+            statistics_store.get_results()
+        });
+
+    println!();
+    match results {
+        Ok(results) => println!("ARCHIVE {}", format_statistics(&Statistics::new(&results, today), 30)),
+        Err(error) => println!("Error updating the archive statistics:\n{}", error)
+    }
+}
+
+/// Warn the player if only a few solutions have not been the word of a day yet.
+/// After every solution has been used, the words of previous days are repeated.
+/// Dictionaries that don't remember the words of previous days are skipped.
+fn warn_unused_words(dictionary: &dyn Dictionary, word_len: usize) {
+    if !dictionary.records_usage() {
+        return;
+    }
+
+    let filter = WordFilter::new()
+        .with_word_len(word_len)
+        .with_role(WordRole::Solution)
+        .with_used(false);
 
-    // check for full match
-    if String::from(solution_word).eq(guessed_word) {
-        return true;
+    match dictionary.count_words(&filter) {
+        Ok(0) => println!("Every word with {} characters has been used, the words of previous days are repeated. Import more words to get new ones.", word_len),
+        Ok(count) if count <= UNUSED_WORDS_WARNING => println!("Only {} words with {} characters remain unused. Import more words before they start to repeat.", count, word_len),
+        Ok(_) => (),
+        Err(error) => println!("Error counting the unused words:\n{}", error)
     }
+}
+
+/// Print the shareable result of the game or write it to share_file
+fn share(game: &Game, played_at: NaiveDate, share_file: &Option<String>) {
+    match share_file {
+        None => println!("{}", format_share(game, played_at, Term::stdout().features().wants_emoji())),
+        Some(path) => match fs::write(path, format_share(game, played_at, true)) {
+            Ok(_) => println!("Your result has been written to {}.", path),
+            Err(error) => println!("Error when writing your result to {}:\n{}", path, error)
+        }
+    }
+}
+
+/// Read a line from stdin and polish it. Returns [None] if stdin has been closed.
+/// Input that can't be read, e.g. because it is not valid UTF-8, ends the game like a dictionary error.
+///
+/// # Arguments
+/// * `app_language` - The language of the game, see [replace_unicode]
+fn read_input(app_language: AppLanguage) -> Option<String> {
+    let mut input: String = String::new();
 
-    false
+    match stdin().read_line(&mut input) {
+        Ok(0) => None,
+        Ok(_) => Some(replace_unicode(input.to_lowercase().trim(), app_language)),
+        Err(error) => {
+            println!("Error reading your input:\n{}", error);
+            exit(1);
+        }
+    }
 }
 
-fn print_welcome() {
-    println!(r#"
+fn print_welcome(word_len: usize, max_attempts: usize, practice: bool) {
+    print!(r#"
 ____    __    ____  ______   .______       _______   __       _______        .______          _______.
 \   \  /  \  /   / /  __  \  |   _  \     |       \ |  |     |   ____|       |   _  \        /       |
  \   \/    \/   / |  |  |  | |  |_)  |    |  .--.  ||  |     |  |__    ______|  |_)  |      |   (----`
@@ -139,38 +443,12 @@ ____    __    ____  ______   .______       _______   __       _______        .__
    \    /\    /   |  `--'  | |  |\  \----.|  '--'  ||  `----.|  |____        |  |\  \----.----)   |
     \__/  \__/     \______/  | _| `._____||_______/ |_______||_______|       | _| `._____|_______/
 
-Welcome! Guess today's word in 6 guesses.
-_ _ _ _ _
-    "#)
+"#);
+    match practice {
+        true => println!("Welcome to practice mode! Guess as many words as you like in {} guesses each.", max_attempts),
+        false => println!("Welcome! Guess today's word in {} guesses.", max_attempts)
+    }
+    println!("Enter ? for a hint.");
+    println!("{}", vec!["_"; word_len].join(" "));
+    println!();
 }
-
-#[cfg(test)]
-use fancy_hangman::lang::locale::AppLanguage;
-
-#[cfg(test)]
-#[test]
-fn test_validate_user_input() {
-    assert!(validate_user_input(
-        replace_unicode("schön", AppLanguage::DE).as_str(), 6
-    ));
-
-    assert!(validate_user_input(
-        replace_unicode("schön", AppLanguage::EN).as_str(), 5
-    ));
-
-    assert!(validate_user_input(
-        replace_unicode("lüge", AppLanguage::DE).as_str(), 5
-    ));
-
-    assert!(validate_user_input(
-        replace_unicode("lüge", AppLanguage::EN).as_str(), 4
-    ));
-
-    assert!(validate_user_input(
-        replace_unicode("howdy", AppLanguage::DE).as_str(), 5
-    ));
-
-    assert!(validate_user_input(
-        replace_unicode("howdy", AppLanguage::EN).as_str(), 5
-    ));
-}
\ No newline at end of file
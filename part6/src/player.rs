@@ -0,0 +1,28 @@
+use std::env;
+
+use dotenv::dotenv;
+
+/// The name used if no player is given and the name of the OS user is unknown
+const DEFAULT_PLAYER: &str = "player";
+
+/// Return the name of the player given by player_flag. If it is not set, `WORDLE_PLAYER` is used,
+/// defaulting to the name of the OS user.
+pub fn get_player_name(player_flag: Option<String>) -> String {
+    dotenv().ok();
+
+    player_flag
+        .or_else(|| env::var("WORDLE_PLAYER").ok())
+        .or_else(|| env::var("USER").ok())
+        .or_else(|| env::var("USERNAME").ok())
+        .map(|name| String::from(name.trim()))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_PLAYER))
+}
+
+#[cfg(test)]
+#[test]
+fn test_get_player_name() {
+    assert_eq!(get_player_name(Some(String::from("alice"))), "alice");
+    assert_eq!(get_player_name(Some(String::from(" bob "))), "bob");
+    assert!(!get_player_name(None).is_empty());
+}
@@ -0,0 +1,217 @@
+use chrono::NaiveDate;
+
+use crate::db::db_statistics::DbStatisticsStore;
+use crate::dictionary::{DictionaryError, get_database_url};
+use crate::lang::locale::AppLanguage;
+use crate::text::text_statistics::TextStatisticsStore;
+
+/// Provides basic functions for reading and writing game results
+pub trait StatisticsStore {
+    fn record_result(&self, result: GameResult) -> Result<(), DictionaryError>;
+    fn get_results(&self) -> Result<Vec<GameResult>, DictionaryError>;
+}
+
+/// Distinguishes results of the live daily game from replayed puzzles, which are counted separately
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum ResultKind {
+    /// The puzzle of the day it was played on
+    Daily,
+    /// The puzzle of a previous day, see the archive mode
+    Archive
+}
+
+/// Represents the result of a finished game
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameResult {
+    pub played_at: NaiveDate,
+    pub won: bool,
+    pub attempts: usize
+}
+
+/// Statistics aggregated from all [GameResult] of a player
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+    pub played: usize,
+    pub won: usize,
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// Number of games won per number of guesses, starting with games won with the first guess
+    pub distribution: Vec<usize>
+}
+
+impl Statistics {
+    /// Aggregate game results. A streak is a sequence of won games without a day in between.
+    /// The current streak is lost if the last game was won before yesterday.
+    ///
+    /// # Arguments
+    /// * `results` - The game results, ordered by the day they were played at
+    /// * `today` - The current day
+    pub fn new(results: &[GameResult], today: NaiveDate) -> Statistics {
+        let mut statistics = Statistics {
+            played: results.len(),
+            won: 0,
+            current_streak: 0,
+            max_streak: 0,
+            distribution: vec![0; 6]
+        };
+
+        let mut last_won_at: Option<NaiveDate> = None;
+
+        for result in results {
+            if result.won {
+                statistics.won += 1;
+
+                if statistics.distribution.len() < result.attempts {
+                    statistics.distribution.resize(result.attempts, 0);
+                }
+
+                if result.attempts > 0 {
+                    statistics.distribution[result.attempts - 1] += 1;
+                }
+
+                statistics.current_streak = match last_won_at {
+                    Some(day) if (result.played_at - day).num_days() <= 1 => statistics.current_streak + 1,
+                    _ => 1
+                };
+                statistics.max_streak = statistics.max_streak.max(statistics.current_streak);
+
+                last_won_at = Some(result.played_at);
+            } else {
+                statistics.current_streak = 0;
+                last_won_at = None;
+            }
+        }
+
+        if let Some(day) = last_won_at {
+            if (today - day).num_days() > 1 {
+                statistics.current_streak = 0;
+            }
+        }
+
+        statistics
+    }
+
+    /// The percentage of games won, rounded down
+    pub fn win_percentage(&self) -> usize {
+        match self.played {
+            0 => 0,
+            played => self.won * 100 / played
+        }
+    }
+}
+
+/// Format statistics and the guess distribution as a bar chart
+///
+/// # Arguments
+/// * `statistics` - The [Statistics] to format
+/// * `bar_width` - The width of the longest bar in characters
+pub fn format_statistics(statistics: &Statistics, bar_width: usize) -> String {
+    let mut lines: Vec<String> = vec![
+        String::from("STATISTICS"),
+        format!(
+            "Played: {}  Win %: {}  Current streak: {}  Max streak: {}",
+            statistics.played, statistics.win_percentage(), statistics.current_streak, statistics.max_streak
+        ),
+        String::new(),
+        String::from("GUESS DISTRIBUTION")
+    ];
+
+    let max_count = statistics.distribution.iter().copied().max().unwrap_or(0).max(1);
+
+    for (i, count) in statistics.distribution.iter().enumerate() {
+        let width = (count * bar_width / max_count).max(1);
+
+        lines.push(format!("{} |{} {}", i + 1, "█".repeat(width), count));
+    }
+
+    lines.join("\n")
+}
+
+/// Open the statistics store matching dictionary_flag, see [crate::dictionary::get_dictionary].
+/// Only results of the kind given are recorded and read. The database keeps the results of every
+/// player apart, the text files are not shared, so the player is ignored.
+pub fn get_statistics_store(app_language: AppLanguage, dictionary_flag: String, kind: ResultKind, player_name: &str) -> Result<Box<dyn StatisticsStore>, DictionaryError> {
+    match dictionary_flag.as_str() {
+        "db" | "postgres" => Ok(Box::new(DbStatisticsStore::new(get_database_url(&dictionary_flag)?, app_language, kind)?
+            .with_player(player_name)?)),
+        _ => match kind {
+            ResultKind::Daily => Ok(Box::new(TextStatisticsStore::new(format!("res/statistics_{}.txt", app_language.to_string().to_lowercase())))),
+            ResultKind::Archive => Ok(Box::new(TextStatisticsStore::new(format!("res/statistics_archive_{}.txt", app_language.to_string().to_lowercase()))))
+        }
+    }
+}
+
+#[cfg(test)]
+fn day(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2022, 7, day).unwrap()
+}
+
+#[cfg(test)]
+#[test]
+fn test_statistics() {
+    let results = vec![
+        GameResult { played_at: day(1), won: true, attempts: 3 },
+        GameResult { played_at: day(2), won: true, attempts: 4 },
+        GameResult { played_at: day(3), won: false, attempts: 6 },
+        GameResult { played_at: day(4), won: true, attempts: 4 },
+        GameResult { played_at: day(5), won: true, attempts: 1 },
+        GameResult { played_at: day(6), won: true, attempts: 4 }
+    ];
+
+    let statistics = Statistics::new(&results, day(6));
+
+    assert_eq!(statistics.played, 6);
+    assert_eq!(statistics.won, 5);
+    assert_eq!(statistics.win_percentage(), 83);
+    assert_eq!(statistics.current_streak, 3);
+    assert_eq!(statistics.max_streak, 3);
+    assert_eq!(statistics.distribution, vec![1, 0, 1, 3, 0, 0]);
+
+    assert_eq!(Statistics::new(&results, day(7)).current_streak, 3);
+    assert_eq!(Statistics::new(&results, day(8)).current_streak, 0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_statistics_streak() {
+    let results = vec![
+        GameResult { played_at: day(1), won: true, attempts: 2 },
+        GameResult { played_at: day(2), won: true, attempts: 2 },
+        GameResult { played_at: day(2), won: true, attempts: 7 },
+        GameResult { played_at: day(4), won: true, attempts: 2 }
+    ];
+
+    let statistics = Statistics::new(&results, day(4));
+
+    assert_eq!(statistics.current_streak, 1);
+    assert_eq!(statistics.max_streak, 3);
+    assert_eq!(statistics.distribution, vec![0, 3, 0, 0, 0, 0, 1]);
+
+    let statistics = Statistics::new(&[], day(4));
+
+    assert_eq!(statistics.played, 0);
+    assert_eq!(statistics.win_percentage(), 0);
+    assert_eq!(statistics.current_streak, 0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_statistics() {
+    let statistics = Statistics {
+        played: 4,
+        won: 3,
+        current_streak: 1,
+        max_streak: 2,
+        distribution: vec![0, 1, 2]
+    };
+
+    assert_eq!(format_statistics(&statistics, 4), "STATISTICS
+Played: 4  Win %: 75  Current streak: 1  Max streak: 2
+
+GUESS DISTRIBUTION
+1 |█ 0
+2 |██ 1
+3 |████ 2");
+}
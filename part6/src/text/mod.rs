@@ -1 +1,2 @@
-pub mod text_dictionary;
\ No newline at end of file
+pub mod text_dictionary;
+pub mod text_statistics;
\ No newline at end of file
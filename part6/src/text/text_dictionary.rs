@@ -1,117 +1,238 @@
+use std::cell::{Ref, RefCell};
+use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, LineWriter, Result, Write};
+use std::io::{BufRead, BufReader, ErrorKind, LineWriter, Write};
+use chrono::NaiveDate;
 use rand::seq::IteratorRandom;
-use crate::dictionary::{Dictionary, DictionaryEntry};
-
-/// Provides a dictionary represented by a text file
+use crate::dictionary::{Dictionary, DictionaryEntry, DictionaryError, matches_pattern, parse_word_role, WordFilter, WordRole};
+use crate::game::daily::daily_index;
+use crate::lang::locale::AppLanguage;
+
+/// Provides a dictionary represented by a text file.
+///
+/// Every line holds a word, optionally followed by its [WordRole], e.g. `rusty` or `aahed guess`.
+/// Words without a role are [WordRole::Solution].
+///
+/// The file is read once into memory the first time a word is requested. Words created
+/// afterwards are kept in memory and written to the file with [Dictionary::flush].
+/// A missing file is treated like an empty one and created on the first flush.
 pub struct TextDictionary {
-    pub dictionary_file_path: String
+    pub dictionary_file_path: String,
+    app_language: AppLanguage,
+    words: RefCell<Option<HashMap<String, WordRole>>>,
+    pending: RefCell<Vec<(String, WordRole)>>
 }
 
 impl TextDictionary {
-    /// Creates dictionary based on the file given
+    /// Creates dictionary based on the file given. The file is read lazily.
     ///
     /// # Arguments
     /// * `file_path` - A String representing the path to the dictionary file on the filesystem
     pub fn new(file_path: String) -> TextDictionary {
-        TextDictionary { dictionary_file_path: file_path }
+        TextDictionary {
+            dictionary_file_path: file_path,
+            app_language: AppLanguage::EN,
+            words: RefCell::new(None),
+            pending: RefCell::new(Vec::new())
+        }
     }
-}
 
-impl Dictionary for TextDictionary {
-    /// Get [DictionaryEntry] from a random line of the Dictionary using reservoir sampling
-    fn get_random_word(&self) -> Option<DictionaryEntry> {
-        let file_result = File::open(&self.dictionary_file_path);
-
-        match file_result {
-            Ok(file) => {
-                let buf_reader = BufReader::new(file);
-
-                let random_line: Option<Result<String>> = buf_reader
-                    .lines()
-                    .choose(&mut rand::thread_rng());
-
-                match random_line {
-                    Some(line) => Some(DictionaryEntry {
-                        word: line.unwrap(),
-                        guessed: false
-                    }),
-                    None => None
+    /// Set the language of the words, which is part of the selection of the daily word. Defaults to [AppLanguage::EN].
+    pub fn with_language(mut self, app_language: AppLanguage) -> TextDictionary {
+        self.app_language = app_language;
+
+        self
+    }
+
+    /// Creates dictionary based on the file given and reads the file right away
+    ///
+    /// # Arguments
+    /// * `file_path` - A String representing the path to the dictionary file on the filesystem
+    pub fn load(file_path: String) -> Result<TextDictionary, DictionaryError> {
+        let dictionary = TextDictionary::new(file_path);
+        dictionary.words()?;
+
+        Ok(dictionary)
+    }
+
+    /// Check a word against the filter. The text dictionary does not keep track of the word of
+    /// the day or of guessed words, so every word is unused and not guessed.
+    fn matches(&self, filter: &WordFilter, word: &str, role: WordRole) -> bool {
+        filter.language.is_none_or(|app_language| app_language == self.app_language)
+            && filter.word_len.is_none_or(|word_len| word.len() == word_len)
+            && filter.role.is_none_or(|expected_role| expected_role == role)
+            && filter.used != Some(true)
+            && filter.guessed != Some(true)
+            && filter.pattern.as_ref().is_none_or(|pattern| matches_pattern(word, pattern))
+    }
+
+    /// Return the words of the dictionary, reading the file if it has not been read yet
+    fn words(&self) -> Result<Ref<'_, HashMap<String, WordRole>>, DictionaryError> {
+        if self.words.borrow().is_none() {
+            let mut words: HashMap<String, WordRole> = HashMap::new();
+
+            let file = match File::open(&self.dictionary_file_path) {
+                Ok(file) => Some(file),
+                Err(error) if error.kind() == ErrorKind::NotFound => None,
+                Err(error) => return Err(DictionaryError::from(error))
+            };
+
+            for line_result in file.into_iter().flat_map(|file| BufReader::new(file).lines()) {
+                let line = line_result?;
+                let mut columns = line.split_whitespace();
+
+                if let Some(word) = columns.next() {
+                    words.insert(String::from(word), parse_word_role(columns.next().unwrap_or_default()));
                 }
             }
-            Err(e) => {
-                println!("Error reading from the Dictionary:\n{}", e);
-                None
-            }
+
+            *self.words.borrow_mut() = Some(words);
         }
+
+        Ok(Ref::map(self.words.borrow(), |words| words.as_ref().unwrap()))
     }
+}
 
-    /// Search the Dictionary for a specific [DictionaryEntry]
-    fn find_word(&self, text: &str) -> Option<DictionaryEntry> {
-        let file_result: Result<File> = File::open(&self.dictionary_file_path);
+impl Dictionary for TextDictionary {
+    /// Get [DictionaryEntry] from a random solution of the Dictionary with a length of word_len characters
+    fn get_random_word(&self, word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        let random_word: Option<String> = self.words()?
+            .iter()
+            .filter(|(word, role)| word.len() == word_len && **role == WordRole::Solution)
+            .map(|(word, _)| word)
+            .choose(&mut rand::thread_rng())
+            .cloned();
+
+        Ok(random_word.map(|word| DictionaryEntry {
+            word,
+            guessed: false,
+            lost: false,
+            role: WordRole::Solution
+        }))
+    }
 
-        match file_result {
-            Ok(file) => {
-                let buf_reader = BufReader::new(file);
-                let mut word_option: Option<DictionaryEntry> = None;
+    /// Get the solution of the day from the solutions with a length of word_len characters in alphabetical order
+    fn get_daily_word(&self, day: NaiveDate, word_len: usize, seed: &str) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        let words = self.words()?;
+        let mut solutions: Vec<&String> = words.iter()
+            .filter(|(word, role)| word.len() == word_len && **role == WordRole::Solution)
+            .map(|(word, _)| word)
+            .collect();
+        solutions.sort();
+
+        Ok(daily_index(day, self.app_language, seed, solutions.len()).map(|index| DictionaryEntry {
+            word: String::from(solutions[index]),
+            guessed: false,
+            lost: false,
+            role: WordRole::Solution
+        }))
+    }
 
-                for line_result in buf_reader.lines() {
-                    let line = line_result.unwrap();
+    /// Search the Dictionary for a specific [DictionaryEntry]
+    fn find_word(&self, text: &str) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        Ok(self.words()?
+            .get_key_value(text)
+            .map(|(word, role)| DictionaryEntry {
+                word: String::from(word),
+                guessed: false,
+                lost: false,
+                role: *role
+            }))
+    }
 
-                    if text.eq(line.trim()) {
-                        word_option = Some(DictionaryEntry {
-                            word: String::from(line),
-                            guessed: false
-                        });
+    fn get_entries(&self, filter: &WordFilter) -> Result<Vec<DictionaryEntry>, DictionaryError> {
+        let mut words: Vec<DictionaryEntry> = self.words()?
+            .iter()
+            .filter(|(word, role)| self.matches(filter, word, **role))
+            .map(|(word, role)| DictionaryEntry {
+                word: String::from(word),
+                guessed: false,
+                lost: false,
+                role: *role
+            })
+            .collect();
+        words.sort_by(|a, b| a.word.cmp(&b.word));
+
+        Ok(words)
+    }
 
-                        break;
-                    }
-                }
+    fn count_words(&self, filter: &WordFilter) -> Result<usize, DictionaryError> {
+        Ok(self.words()?
+            .iter()
+            .filter(|(word, role)| self.matches(filter, word, **role))
+            .count())
+    }
 
-                word_option
-            }
-            Err(error) => {
-                println!("Error when looking for '{}' in the Dictionary:\n{}", text, error);
-                None
-            }
+    /// Add a word to the Dictionary. The word is written to the file on [Dictionary::flush].
+    fn create_word(&self, word_entry: DictionaryEntry) -> Result<DictionaryEntry, DictionaryError> {
+        if self.find_word(&word_entry.word)?.is_some() {
+            return Err(DictionaryError::AlreadyExists(word_entry.word));
         }
+
+        if let Some(words) = self.words.borrow_mut().as_mut() {
+            words.insert(String::from(&word_entry.word), word_entry.role);
+        }
+        self.pending.borrow_mut().push((String::from(&word_entry.word), word_entry.role));
+
+        Ok(DictionaryEntry {
+            word: word_entry.word,
+            guessed: false,
+            lost: false,
+            role: word_entry.role
+        })
     }
 
-    fn create_word(&self, word_entry: DictionaryEntry) -> Option<DictionaryEntry> {
-        match self.find_word(&word_entry.word) {
-            Some(_) => {
-                println!("'{}' already exists in the dictionary.", &word_entry.word);
-
-                None
-            },
-            None => {
-                let file_result = OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(&self.dictionary_file_path);
-
-                match file_result {
-                    Ok(file) => {
-                        let mut writer: LineWriter<File> = LineWriter::new(file);
-                        writer.write(&word_entry.word.as_ref()).unwrap();
-                        writer.write(b"\n").unwrap();
-
-                        println!("Added '{}' to the dictionary!", &word_entry.word);
-
-                        Some(DictionaryEntry{
-                            word: word_entry.word,
-                            guessed: false
-                        })
-                    }
-                    Err(e) => {
-                        println!("Error when writing '{}' to the dictionary:\n{}", &word_entry.word, e);
-
-                        None
-                    }
-                }
+    /// The text dictionary does not remember which words have been played
+    fn get_archive_days(&self, _before: NaiveDate, _word_len: usize) -> Result<Vec<NaiveDate>, DictionaryError> {
+        Ok(Vec::new())
+    }
+
+    fn get_archive_word(&self, _day: NaiveDate, _word_len: usize) -> Result<Option<DictionaryEntry>, DictionaryError> {
+        Ok(None)
+    }
+
+    fn guessed_word(&self, _word_entry: DictionaryEntry) -> Result<(), DictionaryError> {
+        Ok(())
+    }
+
+    fn lost_word(&self, _word_entry: DictionaryEntry) -> Result<(), DictionaryError> {
+        Ok(())
+    }
+
+    fn get_guesses(&self, _word_entry: &DictionaryEntry) -> Result<Vec<String>, DictionaryError> {
+        Ok(Vec::new())
+    }
+
+    fn save_guess(&self, _word_entry: &DictionaryEntry, _guess: &str) -> Result<(), DictionaryError> {
+        Ok(())
+    }
+
+    /// Append the words created since the last flush to the dictionary file
+    fn flush(&self) -> Result<(), DictionaryError> {
+        let mut pending = self.pending.borrow_mut();
+
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.dictionary_file_path)?;
+
+        let mut writer: LineWriter<File> = LineWriter::new(file);
+
+        for (word, role) in pending.iter() {
+            match role {
+                WordRole::Solution => writer.write_all(word.as_ref())?,
+                _ => writer.write_all(format!("{} {}", word, role).as_ref())?
             }
+            writer.write_all(b"\n")?;
         }
-    }
+        writer.flush()?;
 
-    fn guessed_word(&self, _word_entry: DictionaryEntry) {}
-}
\ No newline at end of file
+        pending.clear();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, LineWriter, Write};
+
+use chrono::NaiveDate;
+
+use crate::dictionary::DictionaryError;
+use crate::statistics::{GameResult, StatisticsStore};
+
+/// Provides a statistics store represented by a text file.
+/// Every line holds a single [GameResult] in the format `<played_at>,<won>,<attempts>`,
+/// e.g. `2022-07-01,true,4`.
+pub struct TextStatisticsStore {
+    pub statistics_file_path: String
+}
+
+impl TextStatisticsStore {
+    /// Creates a statistics store based on the file given. The file is created on the first write.
+    ///
+    /// # Arguments
+    /// * `file_path` - A String representing the path to the statistics file on the filesystem
+    pub fn new(file_path: String) -> TextStatisticsStore {
+        TextStatisticsStore { statistics_file_path: file_path }
+    }
+}
+
+impl StatisticsStore for TextStatisticsStore {
+    /// Append the result to the file. The text dictionary does not remember the games of a day,
+    /// so the word of the day can be played again. Only the first result of a day is recorded.
+    fn record_result(&self, result: GameResult) -> Result<(), DictionaryError> {
+        if self.get_results()?.iter().any(|recorded| recorded.played_at == result.played_at) {
+            return Ok(());
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.statistics_file_path)?;
+
+        let mut writer: LineWriter<File> = LineWriter::new(file);
+        writeln!(writer, "{},{},{}", result.played_at, result.won, result.attempts)?;
+
+        Ok(())
+    }
+
+    /// Read all results. Lines that can not be parsed are skipped.
+    /// A missing file is treated like an empty one.
+    fn get_results(&self) -> Result<Vec<GameResult>, DictionaryError> {
+        let file = match File::open(&self.statistics_file_path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(DictionaryError::from(error))
+        };
+
+        let mut results = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            if let Some(result) = parse_result(&line?) {
+                results.push(result);
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+fn parse_result(line: &str) -> Option<GameResult> {
+    let mut fields = line.trim().split(',');
+
+    Some(GameResult {
+        played_at: NaiveDate::parse_from_str(fields.next()?, "%Y-%m-%d").ok()?,
+        won: fields.next()?.parse().ok()?,
+        attempts: fields.next()?.parse().ok()?
+    })
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_result() {
+    assert_eq!(parse_result("2022-07-01,true,4"), Some(GameResult {
+        played_at: NaiveDate::from_ymd_opt(2022, 7, 1).unwrap(),
+        won: true,
+        attempts: 4
+    }));
+    assert_eq!(parse_result("2022-07-01,false,6\r"), Some(GameResult {
+        played_at: NaiveDate::from_ymd_opt(2022, 7, 1).unwrap(),
+        won: false,
+        attempts: 6
+    }));
+    assert_eq!(parse_result("2022-07-01,true"), None);
+    assert_eq!(parse_result(""), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_text_statistics_store() {
+    let file_path = std::env::temp_dir().join(format!("statistics_{}.txt", uuid::Uuid::new_v4()));
+    let statistics_store = TextStatisticsStore::new(String::from(file_path.to_str().unwrap()));
+    let result = GameResult { played_at: NaiveDate::from_ymd_opt(2022, 7, 1).unwrap(), won: true, attempts: 4 };
+
+    assert_eq!(statistics_store.get_results().unwrap(), Vec::new());
+    assert!(statistics_store.record_result(result.clone()).is_ok());
+    assert_eq!(statistics_store.get_results().unwrap(), vec![result.clone()]);
+
+    // playing the word of the day again is not counted
+    assert!(statistics_store.record_result(GameResult { won: false, attempts: 6, ..result.clone() }).is_ok());
+    assert_eq!(statistics_store.get_results().unwrap(), vec![result.clone()]);
+
+    let next_day = GameResult { played_at: NaiveDate::from_ymd_opt(2022, 7, 2).unwrap(), won: false, attempts: 6 };
+    assert!(statistics_store.record_result(next_day.clone()).is_ok());
+    assert_eq!(statistics_store.get_results().unwrap(), vec![result.clone(), next_day]);
+
+    std::fs::remove_file(file_path).unwrap();
+
+    // a directory can't be read or written as a file
+    let directory_store = TextStatisticsStore::new(String::from(std::env::temp_dir().to_str().unwrap()));
+    assert!(matches!(directory_store.record_result(result), Err(DictionaryError::Io(_))));
+    assert!(matches!(directory_store.get_results(), Err(DictionaryError::Io(_))));
+}
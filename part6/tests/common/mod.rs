@@ -0,0 +1,99 @@
+// Every test crate includes the fixtures, but uses only some of them
+#![allow(dead_code)]
+
+use std::env::{self, temp_dir};
+use std::fs::{File, OpenOptions, remove_file};
+use std::io::Write;
+use diesel::connection::SimpleConnection;
+use diesel::{Connection, PgConnection};
+use fancy_hangman::db::connection::DbConnection;
+use uuid::Uuid;
+
+/// The dictionary table of a SQLite database set up with `res/db/create.sql` before the migrations were embedded
+pub const BASELINE_SQLITE_DICTIONARY: &str = "
+    create table dictionary (
+        id integer primary key not null,
+        word varchar not null,
+        used_at DATE null,
+        guessed boolean default 0 not null,
+        language varchar not null
+    );";
+
+/// The dictionary table of a PostgreSQL database set up with `res/db/create_postgres.sql` before the migrations were embedded
+pub const BASELINE_POSTGRES_DICTIONARY: &str = "
+    create table dictionary (
+        id serial primary key,
+        word varchar not null,
+        used_at DATE null,
+        guessed boolean default 'f' not null,
+        language varchar not null
+    );";
+
+pub fn setup() -> String {
+    let tmp_file_name = format!("{}/{}.txt", temp_dir().to_str().unwrap(), Uuid::new_v4());
+
+    File::create(&tmp_file_name).unwrap();
+
+    tmp_file_name
+}
+
+pub fn fill(file_path: &String, sample_words: Vec<&str>) {
+    let file_result =  OpenOptions::new()
+        .append(true)
+        .open(file_path);
+
+    match file_result {
+        Ok(mut file) => {
+            for word in sample_words {
+                file.write_all(word.as_ref()).unwrap();
+                file.write_all(b"\n").unwrap();
+            }
+        }
+        Err(e) => panic!("Error setting up integration test:\n{}", e)
+    };
+}
+
+pub fn get_sample_words() -> Vec<&'static str> {
+    vec!["rusty", "fishy", "busty", "lusty"]
+}
+
+pub fn teardown(file_path: String) {
+    remove_file(file_path).unwrap();
+}
+
+/// Return the path of a new SQLite database, the tables are created by the migrations
+pub fn setup_sqlite() -> String {
+    format!("{}/{}.db", temp_dir().to_str().unwrap(), Uuid::new_v4())
+}
+
+/// Create a new schema in the database at `TEST_POSTGRES_URL` and return a url using
+/// that schema together with the name of the schema. The tables are created by the migrations
+pub fn setup_postgres() -> (String, String) {
+    let base_url = env::var("TEST_POSTGRES_URL").expect("TEST_POSTGRES_URL must be set to run the postgres tests");
+    let schema = format!("test_{}", Uuid::new_v4().simple());
+
+    let separator = if base_url.contains('?') { "&" } else { "?" };
+    let db_url = format!("{}{}options=-c%20search_path%3D{}", base_url, separator, schema);
+
+    PgConnection::establish(&base_url).unwrap()
+        .batch_execute(&format!("create schema {}", schema)).unwrap();
+
+    (db_url, schema)
+}
+
+pub fn teardown_sqlite(db_path: String) {
+    remove_file(db_path).unwrap();
+}
+
+pub fn teardown_postgres(db_url: &str, schema: &str) {
+    PgConnection::establish(db_url).unwrap()
+        .batch_execute(&format!("drop schema {} cascade", schema)).unwrap();
+}
+
+/// Run the SQL statements given against the database at db_url
+pub fn execute(db_url: &str, sql: &str) {
+    match DbConnection::establish(db_url).unwrap() {
+        DbConnection::Sqlite(conn) => conn.batch_execute(sql).unwrap(),
+        DbConnection::Postgres(conn) => conn.batch_execute(sql).unwrap()
+    }
+}
@@ -0,0 +1,230 @@
+use fancy_hangman::db::db_dictionary::DbDictionary;
+use fancy_hangman::db::db_statistics::DbStatisticsStore;
+use fancy_hangman::dictionary::{CreatedWords, Dictionary, DictionaryEntry, DictionaryError, WordFilter, WordRole};
+use fancy_hangman::game::daily::daily_index;
+use fancy_hangman::lang::locale::AppLanguage;
+use fancy_hangman::statistics::{GameResult, ResultKind, StatisticsStore};
+use chrono::{Duration, NaiveDate};
+
+mod common;
+
+use crate::common::{get_sample_words, setup_postgres, setup_sqlite, teardown_postgres, teardown_sqlite};
+
+/// Run the same checks against every database backend
+fn check_dictionary(dictionary: &dyn Dictionary) {
+    for word in get_sample_words() {
+        assert!(dictionary.create_word(DictionaryEntry { word: String::from(word), guessed: false, lost: false, role: WordRole::Solution }).is_ok());
+    }
+
+    assert!(matches!(
+        dictionary.create_word(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }),
+        Err(DictionaryError::AlreadyExists(_))
+    ));
+
+    assert_eq!(dictionary.find_word("fishy").unwrap().unwrap().word, "fishy");
+    assert!(dictionary.find_word("testy").unwrap().is_none());
+
+    // guess-only words are found, but never drawn
+    assert!(dictionary.create_word(DictionaryEntry { word: String::from("rustic"), guessed: false, lost: false, role: WordRole::Guess }).is_ok());
+    assert_eq!(dictionary.find_word("rustic").unwrap().unwrap().role, WordRole::Guess);
+    assert_eq!(dictionary.find_word("fishy").unwrap().unwrap().role, WordRole::Solution);
+
+    let mut sorted_words = get_sample_words();
+    sorted_words.sort();
+    assert_eq!(dictionary.get_entries(&WordFilter::new().with_word_len(5)).unwrap().iter().map(|entry| entry.word.as_str()).collect::<Vec<&str>>(), sorted_words);
+    assert_eq!(dictionary.get_entries(&WordFilter::new().with_word_len(6)).unwrap().iter().map(|entry| entry.role).collect::<Vec<WordRole>>(), vec![WordRole::Guess]);
+
+    assert!(get_sample_words().contains(&dictionary.get_random_word(5).unwrap().unwrap().word.as_str()));
+    assert!(dictionary.get_random_word(6).unwrap().is_none());
+
+    // the daily word is picked from the solutions in alphabetical order
+    let day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let solution = dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap();
+    assert_eq!(solution.word, sorted_words[daily_index(day, AppLanguage::EN, "seed", 4).unwrap()]);
+    assert_eq!(dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap().word, solution.word);
+    assert!(dictionary.get_daily_word(day, 6, "seed").unwrap().is_none());
+
+    assert!(dictionary.save_guess(&solution, "fishy").is_ok());
+    assert!(dictionary.save_guess(&solution, "lusty").is_ok());
+    assert_eq!(dictionary.get_guesses(&solution).unwrap(), vec!["fishy", "lusty"]);
+
+    assert!(dictionary.guessed_word(solution.clone()).is_ok());
+    assert!(dictionary.find_word(&solution.word).unwrap().unwrap().guessed);
+    assert!(dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap().guessed);
+
+    // after every solution has been used once, the words repeat as a new game
+    let repeated = dictionary.get_daily_word(day + Duration::days(4), 5, "seed").unwrap().unwrap();
+    assert_eq!(repeated.word, solution.word);
+    assert!(!repeated.guessed);
+    assert!(dictionary.get_guesses(&repeated).unwrap().is_empty());
+
+    // previous puzzles are listed newest first, a word repeated later is still the word of its earlier day
+    let second = dictionary.get_daily_word(day + Duration::days(1), 5, "seed").unwrap().unwrap();
+    let third = dictionary.get_daily_word(day + Duration::days(2), 5, "seed").unwrap().unwrap();
+
+    assert_eq!(
+        dictionary.get_archive_days(day + Duration::days(4), 5).unwrap(),
+        vec![day + Duration::days(2), day + Duration::days(1), day]
+    );
+    assert_eq!(dictionary.get_archive_days(day + Duration::days(1), 5).unwrap(), vec![day]);
+    assert!(dictionary.get_archive_days(day + Duration::days(4), 6).unwrap().is_empty());
+
+    assert_eq!(dictionary.get_archive_word(day + Duration::days(1), 5).unwrap().unwrap().word, second.word);
+    assert_eq!(dictionary.get_archive_word(day + Duration::days(2), 5).unwrap().unwrap().word, third.word);
+
+    let first = dictionary.get_archive_word(day, 5).unwrap().unwrap();
+    assert_eq!(first.word, solution.word);
+    assert!(first.guessed);
+    assert!(dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap().guessed);
+
+    // every criterion of a filter narrows down the words
+    assert!(dictionary.guessed_word(second.clone()).is_ok());
+
+    assert_eq!(dictionary.count_words(&WordFilter::new()).unwrap(), 5);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_role(WordRole::Solution)).unwrap(), 4);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_word_len(5).with_used(true)).unwrap(), 3);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_used(false)).unwrap(), 2);
+    assert!(dictionary.records_usage());
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_language(AppLanguage::DE)).unwrap(), 0);
+
+    let guessed = dictionary.get_entries(&WordFilter::new().with_guessed(true)).unwrap();
+    assert_eq!(guessed.len(), 1);
+    assert_eq!(guessed[0].word, second.word);
+
+    let words = |filter: WordFilter| -> Vec<String> {
+        dictionary.get_entries(&filter).unwrap().into_iter().map(|entry| entry.word).collect()
+    };
+    assert_eq!(words(WordFilter::new().with_pattern("*sty")), vec!["busty", "lusty", "rusty"]);
+    assert_eq!(words(WordFilter::new().with_pattern("_ust*")), vec!["busty", "lusty", "rustic", "rusty"]);
+    assert_eq!(words(WordFilter::new().with_pattern("_ust*").with_role(WordRole::Guess)), vec!["rustic"]);
+
+    // `%` and `\` are no wildcards, like in the text dictionary
+    for word in ["rus%y", "rus\\y"] {
+        assert!(dictionary.create_word(DictionaryEntry { word: String::from(word), guessed: false, lost: false, role: WordRole::Guess }).is_ok());
+    }
+    assert_eq!(words(WordFilter::new().with_pattern("rus%y")), vec!["rus%y"]);
+    assert_eq!(words(WordFilter::new().with_pattern("rus\\y")), vec!["rus\\y"]);
+    assert_eq!(words(WordFilter::new().with_pattern("%")), Vec::<String>::new());
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_pattern("rus_y")).unwrap(), 3);
+
+    // the case of a pattern is ignored the same way by every backend
+    assert_eq!(words(WordFilter::new().with_pattern("R_ST*")), vec!["rustic", "rusty"]);
+    assert_eq!(words(WordFilter::new().with_pattern("_uSt*").with_role(WordRole::Guess)), vec!["rustic"]);
+
+    // batches skip existing words and words given twice, across several transactions
+    let batch: Vec<String> = (0..600).map(|i| format!("w{:04}", i))
+        .chain(vec![String::from("rusty"), String::from("w0001")])
+        .collect();
+    let mut word_entries = batch.into_iter()
+        .map(|word| DictionaryEntry { word, guessed: false, lost: false, role: WordRole::Guess });
+
+    assert_eq!(dictionary.create_words(&mut word_entries).unwrap(), CreatedWords { inserted: 600, skipped: 2 });
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_pattern("w*")).unwrap(), 600);
+    assert_eq!(dictionary.find_word("rusty").unwrap().unwrap().role, WordRole::Solution);
+}
+
+/// Every player has a game of their own, but the same word of the day
+fn check_players(db_url: &str) {
+    let alice = DbDictionary::new(String::from(db_url), AppLanguage::EN).unwrap().with_player("alice").unwrap();
+    let bob = DbDictionary::new(String::from(db_url), AppLanguage::EN).unwrap().with_player("bob").unwrap();
+
+    for word in get_sample_words() {
+        assert!(alice.create_word(DictionaryEntry { word: String::from(word), guessed: false, lost: false, role: WordRole::Solution }).is_ok());
+    }
+
+    let day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let solution = alice.get_daily_word(day, 5, "seed").unwrap().unwrap();
+
+    assert!(alice.save_guess(&solution, "fishy").is_ok());
+    assert!(alice.guessed_word(solution.clone()).is_ok());
+
+    let bobs_solution = bob.get_daily_word(day, 5, "seed").unwrap().unwrap();
+    assert_eq!(bobs_solution.word, solution.word);
+    assert!(!bobs_solution.guessed);
+    assert!(bob.get_guesses(&bobs_solution).unwrap().is_empty());
+
+    assert!(bob.save_guess(&bobs_solution, "lusty").is_ok());
+    assert!(bob.lost_word(bobs_solution.clone()).is_ok());
+    assert!(bob.get_daily_word(day, 5, "seed").unwrap().unwrap().lost);
+
+    // a player is found again by name
+    let alice_again = DbDictionary::new(String::from(db_url), AppLanguage::EN).unwrap().with_player("alice").unwrap();
+    let alices_solution = alice_again.get_daily_word(day, 5, "seed").unwrap().unwrap();
+    assert!(alices_solution.guessed);
+    assert!(!alices_solution.lost);
+    assert_eq!(alice_again.get_guesses(&alices_solution).unwrap(), vec!["fishy"]);
+
+    // the shared state of the word is left untouched
+    let shared = DbDictionary::new(String::from(db_url), AppLanguage::EN).unwrap();
+    assert!(!shared.get_daily_word(day, 5, "seed").unwrap().unwrap().guessed);
+    assert!(shared.get_guesses(&solution).unwrap().is_empty());
+
+    // statistics are kept apart, too
+    let alices_store = DbStatisticsStore::new(String::from(db_url), AppLanguage::EN, ResultKind::Daily).unwrap().with_player("alice").unwrap();
+    let bobs_store = DbStatisticsStore::new(String::from(db_url), AppLanguage::EN, ResultKind::Daily).unwrap().with_player("bob").unwrap();
+
+    assert!(alices_store.record_result(GameResult { played_at: day, won: true, attempts: 1 }).is_ok());
+
+    assert_eq!(alices_store.get_results().unwrap(), vec![GameResult { played_at: day, won: true, attempts: 1 }]);
+    assert!(bobs_store.get_results().unwrap().is_empty());
+
+    // the leaderboard reads the results of every player, but not the ones recorded without a player
+    assert!(bobs_store.record_result(GameResult { played_at: day, won: false, attempts: 6 }).is_ok());
+    assert!(DbStatisticsStore::new(String::from(db_url), AppLanguage::EN, ResultKind::Daily).unwrap()
+        .record_result(GameResult { played_at: day, won: true, attempts: 2 }).is_ok());
+
+    assert_eq!(alices_store.get_player_results(None).unwrap(), vec![
+        (String::from("alice"), GameResult { played_at: day, won: true, attempts: 1 }),
+        (String::from("bob"), GameResult { played_at: day, won: false, attempts: 6 })
+    ]);
+    assert!(alices_store.get_player_results(day.succ_opt()).unwrap().is_empty());
+
+    // the statistics of a player agree with the leaderboard
+    assert_eq!(alices_store.get_results().unwrap(), vec![GameResult { played_at: day, won: true, attempts: 1 }]);
+}
+
+#[test]
+fn test_sqlite_dictionary() {
+    let db_path = setup_sqlite();
+
+    let dictionary = DbDictionary::new(db_path.clone(), AppLanguage::EN).unwrap();
+    check_dictionary(&dictionary);
+
+    // migrations that have already been applied are skipped
+    assert!(DbDictionary::new(db_path.clone(), AppLanguage::EN).is_ok());
+
+    teardown_sqlite(db_path);
+}
+
+#[test]
+fn test_sqlite_players() {
+    let db_path = setup_sqlite();
+
+    check_players(&db_path);
+
+    teardown_sqlite(db_path);
+}
+
+/// Requires a running PostgreSQL server, run it with e.g.
+/// `TEST_POSTGRES_URL=postgres://postgres@localhost/wordle_test cargo test -- --ignored`.
+#[test]
+#[ignore = "needs a PostgreSQL server at TEST_POSTGRES_URL"]
+fn test_postgres_dictionary() {
+    let (db_url, schema) = setup_postgres();
+
+    let dictionary = DbDictionary::new(db_url.clone(), AppLanguage::EN).unwrap();
+    check_dictionary(&dictionary);
+
+    teardown_postgres(&db_url, &schema);
+}
+
+/// Requires a running PostgreSQL server, see [test_postgres_dictionary]
+#[test]
+#[ignore = "needs a PostgreSQL server at TEST_POSTGRES_URL"]
+fn test_postgres_players() {
+    let (db_url, schema) = setup_postgres();
+
+    check_players(&db_url);
+
+    teardown_postgres(&db_url, &schema);
+}
@@ -0,0 +1,90 @@
+use chrono::NaiveDate;
+use diesel::result::Error;
+use diesel_migrations::RunMigrationsError;
+use fancy_hangman::db::connection::DbConnection;
+use fancy_hangman::db::db_dictionary::DbDictionary;
+use fancy_hangman::db::db_maintenance::{find_duplicates, is_unique_violation, merge_duplicates};
+use fancy_hangman::dictionary::{Dictionary, DictionaryError, WordRole};
+use fancy_hangman::lang::locale::AppLanguage;
+
+mod common;
+
+use crate::common::{BASELINE_POSTGRES_DICTIONARY, BASELINE_SQLITE_DICTIONARY, execute, setup_postgres, setup_sqlite, teardown_postgres, teardown_sqlite};
+
+/// Store duplicates in the baseline schema, the migrations stop at the unique index until they are merged
+fn check_merge_duplicates(db_url: &str, baseline_sql: &str) {
+    execute(db_url, baseline_sql);
+    execute(db_url, "insert into dictionary (word, used_at, guessed, language) values
+        ('apfel', '2022-06-12', true, 'EN'),
+        ('apfel', '2022-06-10', false, 'EN'),
+        ('apfel', null, false, 'EN'),
+        ('birne', null, false, 'EN'),
+        ('birne', null, false, 'EN'),
+        ('birne', null, false, 'DE'),
+        ('kiwis', null, false, 'EN');");
+
+    assert!(matches!(
+        DbDictionary::new(String::from(db_url), AppLanguage::EN),
+        Err(DictionaryError::Configuration(_))
+    ));
+
+    // the migrations before the unique index have been applied
+    execute(db_url, "update dictionary set role = 'guess' where id = (select min(id) from dictionary where word = 'birne');
+        insert into guesses (dictionary_id, guess) select id, 'zebra' from dictionary where used_at = '2022-06-12';
+        insert into guesses (dictionary_id, guess) select id, 'birne' from dictionary where used_at = '2022-06-10';");
+
+    let conn = DbConnection::establish(db_url).unwrap();
+    assert!(is_unique_violation(&conn.run_migrations().unwrap_err()));
+    let duplicates = find_duplicates(&conn).unwrap();
+    assert_eq!(duplicates.iter().map(|duplicate| duplicate.word.as_str()).collect::<Vec<&str>>(), vec!["apfel", "birne"]);
+    assert_eq!(duplicates[0].removed_ids.len(), 2);
+
+    assert_eq!(merge_duplicates(&conn).unwrap(), duplicates);
+    assert!(find_duplicates(&conn).unwrap().is_empty());
+    assert!(conn.run_migrations().is_ok());
+
+    // the remaining migrations can be applied now
+    let dictionary = DbDictionary::new(String::from(db_url), AppLanguage::EN).unwrap();
+
+    // the earliest day is kept with the guesses of every duplicate, the word stays guessed
+    let apfel = dictionary.get_archive_word(NaiveDate::from_ymd_opt(2022, 6, 10).unwrap(), 5).unwrap().unwrap();
+    assert_eq!(apfel.word, "apfel");
+    assert!(apfel.guessed);
+    assert!(!apfel.lost);
+    assert_eq!(dictionary.get_guesses(&apfel).unwrap(), vec!["zebra", "birne"]);
+    assert!(dictionary.get_archive_word(NaiveDate::from_ymd_opt(2022, 6, 12).unwrap(), 5).unwrap().is_none());
+
+    assert_eq!(dictionary.find_word("birne").unwrap().unwrap().role, WordRole::Solution);
+    assert!(dictionary.find_word("kiwis").unwrap().is_some());
+    assert!(matches!(
+        dictionary.create_word(dictionary.find_word("kiwis").unwrap().unwrap()),
+        Err(DictionaryError::AlreadyExists(_))
+    ));
+}
+
+/// Only a failing unique index means the words have to be merged first
+#[test]
+fn test_is_unique_violation() {
+    assert!(!is_unique_violation(&RunMigrationsError::EmptyMigration));
+    assert!(!is_unique_violation(&RunMigrationsError::QueryError(Error::NotFound)));
+}
+
+#[test]
+fn test_sqlite_merge_duplicates() {
+    let db_path = setup_sqlite();
+
+    check_merge_duplicates(&db_path, BASELINE_SQLITE_DICTIONARY);
+
+    teardown_sqlite(db_path);
+}
+
+/// Requires a running PostgreSQL server, see `test_postgres_dictionary`
+#[test]
+#[ignore = "needs a PostgreSQL server at TEST_POSTGRES_URL"]
+fn test_postgres_merge_duplicates() {
+    let (db_url, schema) = setup_postgres();
+
+    check_merge_duplicates(&db_url, BASELINE_POSTGRES_DICTIONARY);
+
+    teardown_postgres(&db_url, &schema);
+}
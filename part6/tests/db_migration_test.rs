@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+use fancy_hangman::db::db_dictionary::DbDictionary;
+use fancy_hangman::db::db_statistics::DbStatisticsStore;
+use fancy_hangman::dictionary::{Dictionary, WordRole};
+use fancy_hangman::lang::locale::AppLanguage;
+use fancy_hangman::statistics::{GameResult, ResultKind, StatisticsStore};
+
+mod common;
+
+use crate::common::{BASELINE_POSTGRES_DICTIONARY, BASELINE_SQLITE_DICTIONARY, execute, setup_postgres, setup_sqlite, teardown_postgres, teardown_sqlite};
+
+/// A SQLite database set up with diesel cli and `res/db/create.sql` before the migrations were embedded
+const BASELINE_SQLITE_MIGRATIONS: &str = "
+    create table __diesel_schema_migrations (version varchar(50) primary key not null, run_on timestamp not null default current_timestamp);
+    insert into __diesel_schema_migrations (version) values ('20220614104523'), ('20220614105255'), ('20220614110206');";
+
+/// Create the baseline schema, then check the migrations bring it up to date without losing words
+fn check_baseline_upgrade(db_url: &str, baseline_sql: &str) {
+    execute(db_url, baseline_sql);
+    execute(db_url, "insert into dictionary (word, used_at, guessed, language) values ('apfel', '2022-06-15', true, 'DE'), ('birne', null, false, 'DE');");
+
+    let dictionary = DbDictionary::new(String::from(db_url), AppLanguage::DE).unwrap()
+        .with_player("tester").unwrap();
+
+    let apfel = dictionary.find_word("apfel").unwrap().unwrap();
+    assert!(apfel.guessed);
+    assert!(!apfel.lost);
+    assert_eq!(apfel.role, WordRole::Solution);
+
+    // the last day a word was used at becomes part of the archive
+    let day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let apfel_day = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    assert_eq!(dictionary.get_archive_days(day, 5).unwrap(), vec![apfel_day]);
+    assert_eq!(dictionary.get_archive_word(apfel_day, 5).unwrap().unwrap().word, "apfel");
+
+    let solution = dictionary.get_daily_word(day, 5, "").unwrap().unwrap();
+
+    assert!(dictionary.save_guess(&solution, "zebra").is_ok());
+    assert_eq!(dictionary.get_guesses(&solution).unwrap(), vec!["zebra"]);
+    assert!(dictionary.lost_word(solution).is_ok());
+    assert!(dictionary.get_daily_word(day, 5, "").unwrap().unwrap().lost);
+
+    let statistics_store = DbStatisticsStore::new(String::from(db_url), AppLanguage::DE, ResultKind::Daily).unwrap()
+        .with_player("tester").unwrap();
+    assert!(statistics_store.record_result(GameResult { played_at: day, won: false, attempts: 6 }).is_ok());
+    assert_eq!(statistics_store.get_results().unwrap().len(), 1);
+
+    // the migrations are only applied once
+    assert!(DbDictionary::new(String::from(db_url), AppLanguage::DE).is_ok());
+}
+
+#[test]
+fn test_sqlite_baseline_upgrade() {
+    let db_path = setup_sqlite();
+
+    check_baseline_upgrade(&db_path, &format!("{}{}", BASELINE_SQLITE_MIGRATIONS, BASELINE_SQLITE_DICTIONARY));
+
+    teardown_sqlite(db_path);
+}
+
+/// Requires a running PostgreSQL server, see `test_postgres_dictionary`
+#[test]
+#[ignore = "needs a PostgreSQL server at TEST_POSTGRES_URL"]
+fn test_postgres_baseline_upgrade() {
+    let (db_url, schema) = setup_postgres();
+
+    check_baseline_upgrade(&db_url, BASELINE_POSTGRES_DICTIONARY);
+
+    teardown_postgres(&db_url, &schema);
+}
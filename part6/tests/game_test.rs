@@ -0,0 +1,125 @@
+use fancy_hangman::dictionary::{DictionaryEntry, WordRole};
+use fancy_hangman::game::engine::{Game, GameState, GuessError};
+use fancy_hangman::game::hard_mode::HardModeViolation;
+use fancy_hangman::text::text_dictionary::TextDictionary;
+
+mod common;
+
+use crate::common::{fill, get_sample_words, setup, teardown};
+
+#[test]
+fn test_win() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+    let mut game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 6);
+
+    assert!(game.submit_guess("fishy", &dictionary).is_ok());
+    assert_eq!(game.state(), GameState::InProgress);
+    assert_eq!(game.remaining_attempts(), 5);
+
+    assert!(game.submit_guess("rusty", &dictionary).is_ok());
+    assert!(game.is_won());
+    assert_eq!(game.guesses().len(), 2);
+
+    assert!(matches!(game.submit_guess("lusty", &dictionary), Err(GuessError::GameOver)));
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_lose() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+    let mut game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 2);
+
+    assert!(game.submit_guess("fishy", &dictionary).is_ok());
+    assert!(game.submit_guess("busty", &dictionary).is_ok());
+
+    assert!(game.is_lost());
+    assert_eq!(game.remaining_attempts(), 0);
+    assert!(matches!(game.submit_guess("rusty", &dictionary), Err(GuessError::GameOver)));
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_rejected_guesses() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+    let mut game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 6);
+
+    assert!(matches!(game.submit_guess("rust", &dictionary), Err(GuessError::InvalidLength { expected: 5, actual: 4 })));
+    assert!(matches!(game.submit_guess("testy", &dictionary), Err(GuessError::NotInWordList)));
+    assert_eq!(game.remaining_attempts(), 6);
+    assert!(game.guesses().is_empty());
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_hard_mode() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+    let mut game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 6).with_hard_mode(true);
+
+    assert!(game.submit_guess("fishy", &dictionary).is_ok());
+    assert!(matches!(
+        game.submit_guess("rusty", &dictionary).map(|feedback| feedback.is_solved()),
+        Ok(true)
+    ));
+
+    let mut game = Game::new(DictionaryEntry { word: String::from("busty"), guessed: false, lost: false, role: WordRole::Solution }, 6).with_hard_mode(true);
+
+    assert!(game.submit_guess("lusty", &dictionary).is_ok());
+    assert!(matches!(
+        game.submit_guess("fishy", &dictionary),
+        Err(GuessError::HardMode(HardModeViolation::MissingCorrect { position: 2, letter: 'u' }))
+    ));
+    assert_eq!(game.remaining_attempts(), 5);
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_already_guessed() {
+    let game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: true, lost: false, role: WordRole::Solution }, 6);
+
+    assert!(game.is_won());
+
+    let game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: true, role: WordRole::Solution }, 6);
+
+    assert!(game.is_lost());
+}
+
+#[test]
+fn test_restore_guesses() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+    let guesses = vec![String::from("fishy"), String::from("busty")];
+    let mut game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 3)
+        .with_guesses(&guesses);
+
+    assert_eq!(game.state(), GameState::InProgress);
+    assert_eq!(game.remaining_attempts(), 1);
+    assert_eq!(game.guesses()[1].word(), "busty");
+
+    assert!(game.submit_guess("lusty", &dictionary).is_ok());
+    assert!(game.is_lost());
+
+    let game = Game::new(DictionaryEntry { word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }, 6)
+        .with_guesses(&[String::from("fishy"), String::from("rusty")]);
+
+    assert!(game.is_won());
+
+    teardown(file_path);
+}
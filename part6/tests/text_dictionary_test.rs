@@ -1,7 +1,16 @@
+// the baseline tests assert on constants in the arms of a match
+#![allow(clippy::assertions_on_constants)]
+
+use std::env::temp_dir;
+
+use chrono::NaiveDate;
+use fancy_hangman::lang::locale::{AppLanguage, replace_unicode};
 use fancy_hangman::text::text_dictionary::TextDictionary;
-use fancy_hangman::dictionary::{Dictionary, DictionaryEntry};
+use fancy_hangman::dictionary::{CreatedWords, Dictionary, DictionaryEntry, DictionaryError, WordFilter, WordRole};
+
+mod common;
 
-use crate::tools::{fill, get_sample_words, setup, teardown};
+use crate::common::{fill, get_sample_words, setup, teardown};
 
 
 #[test]
@@ -10,19 +19,19 @@ fn test_create_word() {
 
     let dictionary = TextDictionary::new(file_path.clone());
 
-    match dictionary.create_word(DictionaryEntry{ word: String::from("rusty"), guessed: false }) {
-        None => assert!(false),
-        Some(_) => assert!(true)
+    match dictionary.create_word(DictionaryEntry{ word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }) {
+        Err(_) => assert!(false),
+        Ok(_) => assert!(true)
     }
 
-    match dictionary.create_word(DictionaryEntry{ word: String::from("testy"), guessed: false }) {
-        None => assert!(false),
-        Some(_) => assert!(true)
+    match dictionary.create_word(DictionaryEntry{ word: String::from("testy"), guessed: false, lost: false, role: WordRole::Solution }) {
+        Err(_) => assert!(false),
+        Ok(_) => assert!(true)
     }
 
-    match dictionary.create_word(DictionaryEntry{ word: String::from("rusty"), guessed: false }) {
-        None => assert!(true),
-        Some(_) => assert!(false)
+    match dictionary.create_word(DictionaryEntry{ word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }) {
+        Err(_) => assert!(true),
+        Ok(_) => assert!(false)
     }
 
     teardown(file_path);
@@ -36,7 +45,7 @@ fn test_find_word() {
     let dictionary = TextDictionary::new(file_path.clone());
 
     for word_str in get_sample_words() {
-        match dictionary.find_word(word_str) {
+        match dictionary.find_word(word_str).unwrap() {
             Some(word) => assert_eq!(word_str, word.word),
             None => assert!(false)
         }
@@ -52,7 +61,7 @@ fn test_find_word_negative() {
     let dictionary = TextDictionary::new(file_path.clone());
 
     for word_str in get_sample_words() {
-        match dictionary.find_word(word_str) {
+        match dictionary.find_word(word_str).unwrap() {
             Some(_) => assert!(false),
             None => assert!(true)
         }
@@ -69,7 +78,7 @@ fn test_read_random_word() {
 
     let dictionary = TextDictionary::new(file_path.clone());
 
-    match dictionary.get_random_word() {
+    match dictionary.get_random_word(5).unwrap() {
         Some(word) =>
             assert!(get_sample_words().contains(&word.word.as_str())),
         None => assert!(false)
@@ -84,7 +93,7 @@ fn test_read_random_word_negative() {
 
     let dictionary = TextDictionary::new(file_path.clone());
 
-    match dictionary.get_random_word() {
+    match dictionary.get_random_word(5).unwrap() {
         Some(_) => assert!(false),
         None => assert!(true)
     }
@@ -92,41 +101,194 @@ fn test_read_random_word_negative() {
     teardown(file_path);
 }
 
-mod tools {
-    use std::env::temp_dir;
-    use std::fs::{File, OpenOptions, remove_file};
-    use std::io::Write;
-    use uuid::Uuid;
+#[test]
+fn test_read_random_word_length() {
+    let file_path = setup();
 
-    pub fn setup() -> String {
-        let tmp_file_name = format!("{}/{}.txt", temp_dir().to_str().unwrap(), Uuid::new_v4());
+    fill(&file_path, vec!["rust", "rusty", "rustic"]);
 
-        File::create(&tmp_file_name).unwrap();
+    let dictionary = TextDictionary::new(file_path.clone());
 
-        tmp_file_name
+    match dictionary.get_random_word(4).unwrap() {
+        Some(word) => assert_eq!(word.word, "rust"),
+        None => panic!("No word was read from the dictionary")
     }
 
-    pub fn fill(file_path: &String, sample_words: Vec<&str>) {
-        let file_result =  OpenOptions::new()
-            .append(true)
-            .open(file_path);
-
-        match file_result {
-            Ok(mut file) => {
-                for word in sample_words {
-                    file.write(word.as_ref()).unwrap();
-                    file.write(b"\n").unwrap();
-                }
-            }
-            Err(e) => panic!("Error setting up integration test:\n{}", e)
-        };
+    match dictionary.get_random_word(6).unwrap() {
+        Some(word) => assert_eq!(word.word, "rustic"),
+        None => panic!("No word was read from the dictionary")
     }
 
-    pub fn get_sample_words() -> Vec<&'static str> {
-        vec!["rusty", "fishy", "busty", "lusty"]
+    assert!(dictionary.get_random_word(7).unwrap().is_none());
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_missing_file() {
+    let dictionary = TextDictionary::new(String::from("does/not/exist.txt"));
+
+    assert!(dictionary.find_word("rusty").unwrap().is_none());
+    assert!(dictionary.get_random_word(5).unwrap().is_none());
+
+    assert!(TextDictionary::load(String::from("does/not/exist.txt")).is_ok());
+
+    // other errors are still reported
+    assert!(matches!(TextDictionary::load(String::from(temp_dir().to_str().unwrap())), Err(DictionaryError::Io(_))));
+}
+
+#[test]
+fn test_flush_missing_file() {
+    let file_path = setup();
+    teardown(file_path.clone());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+
+    assert!(dictionary.create_word(DictionaryEntry{ word: String::from("rusty"), guessed: false, lost: false, role: WordRole::Solution }).is_ok());
+    assert!(dictionary.flush().is_ok());
+
+    assert!(TextDictionary::new(file_path.clone()).find_word("rusty").unwrap().is_some());
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_daily_word() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let day = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap();
+    let dictionary = TextDictionary::new(file_path.clone()).with_language(AppLanguage::DE);
+    let word = dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap().word;
+
+    // the order of the file does not matter
+    let reversed_path = setup();
+    fill(&reversed_path, get_sample_words().into_iter().rev().collect());
+
+    let reversed = TextDictionary::new(reversed_path.clone()).with_language(AppLanguage::DE);
+    assert_eq!(reversed.get_daily_word(day, 5, "seed").unwrap().unwrap().word, word);
+
+    // every word is used once before words repeat
+    let mut words: Vec<String> = day.iter_days()
+        .take(4)
+        .map(|day| dictionary.get_daily_word(day, 5, "seed").unwrap().unwrap().word)
+        .collect();
+    words.sort();
+    assert_eq!(words, vec!["busty", "fishy", "lusty", "rusty"]);
+
+    assert!(dictionary.get_daily_word(day, 6, "seed").unwrap().is_none());
+
+    teardown(file_path);
+    teardown(reversed_path);
+}
+
+#[test]
+fn test_word_role() {
+    let file_path = setup();
+    fill(&file_path, vec!["rusty", "fishy guess", "busty   guess", "lusty solution"]);
+
+    let dictionary = TextDictionary::new(file_path.clone());
+
+    for _ in 0..20 {
+        let word = dictionary.get_random_word(5).unwrap().unwrap();
+
+        assert!(word.word == "rusty" || word.word == "lusty");
+        assert_eq!(word.role, WordRole::Solution);
     }
 
-    pub fn teardown(file_path: String) {
-        remove_file(file_path).unwrap();
+    assert_eq!(dictionary.find_word("fishy").unwrap().unwrap().role, WordRole::Guess);
+    assert_eq!(dictionary.find_word("busty").unwrap().unwrap().role, WordRole::Guess);
+    assert_eq!(dictionary.find_word("lusty").unwrap().unwrap().role, WordRole::Solution);
+
+    let words = dictionary.get_entries(&WordFilter::new().with_word_len(5)).unwrap();
+    assert_eq!(words.iter().map(|entry| entry.word.as_str()).collect::<Vec<&str>>(), vec!["busty", "fishy", "lusty", "rusty"]);
+    assert_eq!(words[1].role, WordRole::Guess);
+
+    // the text dictionary does not keep track of used or guessed words
+    assert!(!dictionary.records_usage());
+    assert_eq!(dictionary.count_words(&WordFilter::new()).unwrap(), 4);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_role(WordRole::Guess)).unwrap(), 2);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_used(false)).unwrap(), 4);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_used(true)).unwrap(), 0);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_guessed(true)).unwrap(), 0);
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_language(AppLanguage::DE)).unwrap(), 0);
+    assert_eq!(
+        dictionary.get_entries(&WordFilter::new().with_pattern("_ust_")).unwrap().iter().map(|entry| entry.word.as_str()).collect::<Vec<&str>>(),
+        vec!["busty", "lusty", "rusty"]
+    );
+    assert_eq!(dictionary.count_words(&WordFilter::new().with_pattern("_UST_")).unwrap(), 3);
+
+    assert!(dictionary.create_word(DictionaryEntry{ word: String::from("rustic"), guessed: false, lost: false, role: WordRole::Guess }).is_ok());
+    assert!(dictionary.get_random_word(6).unwrap().is_none());
+    assert!(dictionary.flush().is_ok());
+
+    assert!(std::fs::read_to_string(&file_path).unwrap().ends_with("rustic guess
+"));
+    assert_eq!(TextDictionary::new(file_path.clone()).find_word("rustic").unwrap().unwrap().role, WordRole::Guess);
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_flush() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::load(file_path.clone()).unwrap();
+
+    assert!(dictionary.create_word(DictionaryEntry{ word: String::from("testy"), guessed: false, lost: false, role: WordRole::Solution }).is_ok());
+    assert!(dictionary.find_word("testy").unwrap().is_some());
+
+    // not written to the file before flushing
+    assert!(TextDictionary::new(file_path.clone()).find_word("testy").unwrap().is_none());
+
+    assert!(dictionary.flush().is_ok());
+
+    let reloaded = TextDictionary::new(file_path.clone());
+    assert!(reloaded.find_word("testy").unwrap().is_some());
+    assert!(reloaded.find_word("rusty").unwrap().is_some());
+
+    // flushing again does not write the word twice
+    assert!(dictionary.flush().is_ok());
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap().matches("testy").count(), 1);
+
+    teardown(file_path);
+}
+
+#[test]
+fn test_create_words() {
+    let file_path = setup();
+    fill(&file_path, get_sample_words());
+
+    let dictionary = TextDictionary::new(file_path.clone());
+
+    let mut word_entries = vec!["rusty", "gusty", "gusty", "testy"].into_iter()
+        .map(|word| DictionaryEntry{ word: String::from(word), guessed: false, lost: false, role: WordRole::Solution });
+
+    assert_eq!(dictionary.create_words(&mut word_entries).unwrap(), CreatedWords { inserted: 2, skipped: 2 });
+    assert!(dictionary.flush().is_ok());
+
+    let reloaded = TextDictionary::new(file_path.clone());
+    assert!(reloaded.find_word("gusty").unwrap().is_some());
+    assert!(reloaded.find_word("testy").unwrap().is_some());
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap().matches("gusty").count(), 1);
+
+    teardown(file_path);
+}
+
+/// Every language has a default dictionary with words that are already normalised
+#[test]
+fn test_default_dictionaries() {
+    for app_language in [AppLanguage::EN, AppLanguage::ES, AppLanguage::FR, AppLanguage::IT, AppLanguage::NL] {
+        let dictionary = TextDictionary::new(format!("res/dictionary_{}.txt", app_language.to_string().to_lowercase()))
+            .with_language(app_language);
+
+        let entries = dictionary.get_entries(&WordFilter::new().with_word_len(5)).unwrap();
+
+        assert!(!entries.is_empty());
+
+        for entry in entries {
+            assert_eq!(replace_unicode(&entry.word, app_language), entry.word);
+        }
     }
-}
\ No newline at end of file
+}